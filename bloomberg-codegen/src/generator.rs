@@ -0,0 +1,736 @@
+use crate::model::{DataType, ElementSchema, ServiceSchema, TypeSchema};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+const RESERVED_TYPES: &[&str] = &[
+    "Box", "Err", "None", "Ok", "Option", "Result", "Self", "Some", "String", "Vec",
+];
+
+const ELEMENT: &str = "::bloomberg::element::Element";
+const ERROR: &str = "::bloomberg::Error";
+const GET_VALUE: &str = "::bloomberg::element::GetValue";
+const TO_ELEMENT: &str = "::bloomberg::element::ToElement";
+
+pub struct Generator<'a> {
+    schema: &'a ServiceSchema,
+    operations: Option<Vec<String>>,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(schema: &'a ServiceSchema) -> Self {
+        Generator {
+            schema,
+            operations: None,
+        }
+    }
+
+    pub fn with_operations<I>(mut self, operations: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.operations = Some(operations.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn generate(&self) -> String {
+        let mut out: String = String::new();
+
+        writeln!(
+            out,
+            "// Generated by bloomberg-codegen from `{}`, do not edit.",
+            self.schema.name
+        )
+        .unwrap();
+
+        for name in self.reachable_types() {
+            if let Some(schema) = self.schema.types.get(&name) {
+                match schema.datatype {
+                    DataType::Sequence => self.sequence(&mut out, schema),
+                    DataType::Choice => self.choice(&mut out, schema),
+                    DataType::Enumeration if !schema.constants.is_empty() => {
+                        self.enumeration(&mut out, schema)
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        for operation in self.schema.operations.iter() {
+            if !self.is_selected(&operation.name) {
+                continue;
+            }
+
+            if let Some(type_name) = operation.request.type_name.as_ref() {
+                writeln!(
+                    out,
+                    "\nimpl ::bloomberg::request::Operation for {} {{\n    const SERVICE: &'static str = {:?};\n    const OPERATION: &'static str = {:?};\n}}",
+                    type_ident(type_name),
+                    self.schema.name,
+                    operation.name
+                )
+                .unwrap();
+            }
+        }
+
+        out
+    }
+
+    fn is_selected(&self, operation: &str) -> bool {
+        match self.operations.as_ref() {
+            Some(operations) => operations.iter().any(|o| o == operation),
+            None => true,
+        }
+    }
+
+    fn reachable_types(&self) -> BTreeSet<String> {
+        let mut reachable: BTreeSet<String> = BTreeSet::new();
+        let mut pending: Vec<String> = Vec::new();
+
+        for operation in self.schema.operations.iter() {
+            if self.is_selected(&operation.name) {
+                let elements =
+                    std::iter::once(&operation.request).chain(operation.responses.iter());
+
+                pending.extend(elements.filter_map(|e| e.type_name.clone()));
+            }
+        }
+
+        while let Some(name) = pending.pop() {
+            if !reachable.insert(name.clone()) {
+                continue;
+            }
+
+            if let Some(schema) = self.schema.types.get(&name) {
+                pending.extend(schema.elements.iter().filter_map(|e| e.type_name.clone()));
+            }
+        }
+
+        reachable
+    }
+
+    fn rust_type(&self, element: &ElementSchema) -> Option<String> {
+        let base: String = match element.datatype {
+            DataType::Bool => "bool".to_owned(),
+            DataType::Char => "i8".to_owned(),
+            DataType::Byte | DataType::Int32 => "i32".to_owned(),
+            DataType::Int64 => "i64".to_owned(),
+            DataType::Float32 => "f32".to_owned(),
+            DataType::Float64 | DataType::Decimal => "f64".to_owned(),
+            DataType::String | DataType::ByteArray => "String".to_owned(),
+            DataType::Date => "::bloomberg::chrono::NaiveDate".to_owned(),
+            DataType::Time | DataType::Datetime => "::bloomberg::datetime::Datetime".to_owned(),
+            DataType::Enumeration if self.is_enumeration(element) => {
+                type_ident(element.type_name.as_ref()?)
+            }
+            DataType::Enumeration => "String".to_owned(),
+            DataType::Sequence | DataType::Choice => type_ident(element.type_name.as_ref()?),
+            DataType::CorrelationId | DataType::Unknown => return None,
+        };
+
+        if element.is_array() {
+            Some(format!("Vec<{}>", base))
+        } else if element.is_optional() {
+            Some(format!("Option<{}>", base))
+        } else {
+            Some(base)
+        }
+    }
+
+    fn is_enumeration(&self, element: &ElementSchema) -> bool {
+        element
+            .type_name
+            .as_ref()
+            .and_then(|name| self.schema.types.get(name))
+            .map(|schema| !schema.constants.is_empty())
+            .unwrap_or(false)
+    }
+
+    fn value(&self, element: &ElementSchema, var: &str, by_ref: bool) -> String {
+        let deref: &str = if by_ref { "*" } else { "" };
+        let reference: &str = if by_ref { "" } else { "&" };
+
+        match element.datatype {
+            DataType::String | DataType::ByteArray => format!("&{}[..]", var),
+            DataType::Enumeration if !self.is_enumeration(element) => format!("&{}[..]", var),
+            DataType::Enumeration => format!("{}.as_str()", var),
+            DataType::Date => format!("&::bloomberg::datetime::Datetime::from({}{})", deref, var),
+            DataType::Time | DataType::Datetime => format!("{}{}", reference, var),
+            _ => format!("{}{}", deref, var),
+        }
+    }
+
+    fn fields<'e>(&self, schema: &'e TypeSchema) -> Vec<(&'e ElementSchema, String, String)> {
+        schema
+            .elements
+            .iter()
+            .filter_map(|e| Some((e, field_ident(&e.name), self.rust_type(e)?)))
+            .collect()
+    }
+
+    fn sequence(&self, out: &mut String, schema: &TypeSchema) {
+        out.push('\n');
+
+        let ident: String = type_ident(&schema.name);
+        let fields = self.fields(schema);
+
+        doc(out, "", &schema.description);
+
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, Default)]\npub struct {} {{",
+            ident
+        )
+        .unwrap();
+
+        for (element, field, ty) in fields.iter() {
+            doc(out, "    ", &element.description);
+            writeln!(out, "    pub {}: {},", field, ty).unwrap();
+        }
+
+        writeln!(out, "}}\n\nimpl {} {{", ident).unwrap();
+        writeln!(
+            out,
+            "    pub fn new() -> Self {{\n        {}::default()\n    }}",
+            ident
+        )
+        .unwrap();
+
+        for (element, field, ty) in fields.iter() {
+            let name: &str = field.trim_start_matches("r#");
+
+            let (ty, value): (&str, String) = match ty.strip_prefix("Option<") {
+                Some(inner) if !element.is_array() => {
+                    (&inner[..inner.len() - 1], format!("Some({})", field))
+                }
+                _ => (&ty[..], field.to_owned()),
+            };
+
+            writeln!(
+                out,
+                "\n    pub fn with_{}(mut self, {}: {}) -> Self {{\n        self.{} = {};\n        self\n    }}",
+                name, field, ty, field, value
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "}}\n\nimpl {} for {} {{", TO_ELEMENT, ident).unwrap();
+        writeln!(
+            out,
+            "    fn to_element(&self, element: &mut {}) -> Result<(), {}> {{",
+            ELEMENT, ERROR
+        )
+        .unwrap();
+
+        for (element, field, _) in fields.iter() {
+            self.set_field(out, element, field);
+        }
+
+        writeln!(out, "        Ok(())\n    }}\n}}").unwrap();
+
+        writeln!(out, "\nimpl {} for {} {{", GET_VALUE, ident).unwrap();
+        writeln!(
+            out,
+            "    fn get_at(element: &{}, index: usize) -> Option<Self> {{",
+            ELEMENT
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        let element: {} = element.get_at(index)?;\n\n        Some({} {{",
+            ELEMENT, ident
+        )
+        .unwrap();
+
+        for (element, field, _) in fields.iter() {
+            let getter: String = if element.is_array() {
+                format!(
+                    "element.get_element({:?}).and_then(|e| e.value()).unwrap_or_default()",
+                    element.name
+                )
+            } else if element.is_optional() {
+                format!(
+                    "element.get_element({:?}).and_then(|e| e.value())",
+                    element.name
+                )
+            } else {
+                format!("element.get_element({:?})?.value()?", element.name)
+            };
+
+            writeln!(out, "            {}: {},", field, getter).unwrap();
+        }
+
+        writeln!(out, "        }})\n    }}\n}}").unwrap();
+    }
+
+    fn set_field(&self, out: &mut String, element: &ElementSchema, field: &str) {
+        let child: String = format!(
+            "element.get_element({:?}).ok_or_else(|| {}::NotFound({:?}.to_owned()))?",
+            element.name, ERROR, element.name
+        );
+
+        let complex: bool = element.datatype.is_complex();
+
+        if element.is_array() {
+            writeln!(out, "        let mut array: {} = {};\n", ELEMENT, child).unwrap();
+            writeln!(out, "        for value in self.{}.iter() {{", field).unwrap();
+
+            if complex {
+                writeln!(
+                    out,
+                    "            {}::to_element(value, &mut array.append_element()?)?;",
+                    TO_ELEMENT
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "            array.append({})?;",
+                    self.value(element, "value", true)
+                )
+                .unwrap();
+            }
+
+            writeln!(out, "        }}\n").unwrap();
+        } else if element.is_optional() {
+            writeln!(
+                out,
+                "        if let Some(value) = self.{}.as_ref() {{",
+                field
+            )
+            .unwrap();
+
+            if complex {
+                writeln!(
+                    out,
+                    "            {}::to_element(value, &mut {})?;",
+                    TO_ELEMENT, child
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "            element.set({:?}, {})?;",
+                    element.name,
+                    self.value(element, "value", true)
+                )
+                .unwrap();
+            }
+
+            writeln!(out, "        }}\n").unwrap();
+        } else if complex {
+            writeln!(
+                out,
+                "        {}::to_element(&self.{}, &mut {})?;\n",
+                TO_ELEMENT, field, child
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                out,
+                "        element.set({:?}, {})?;\n",
+                element.name,
+                self.value(element, &format!("self.{}", field), false)
+            )
+            .unwrap();
+        }
+    }
+
+    fn choice(&self, out: &mut String, schema: &TypeSchema) {
+        out.push('\n');
+
+        let ident: String = type_ident(&schema.name);
+        let mut variants: Vec<(&ElementSchema, String, String)> = Vec::new();
+
+        for (element, _, ty) in self.fields(schema) {
+            let ty: String = match ty.strip_prefix("Option<") {
+                Some(inner) => inner[..inner.len() - 1].to_owned(),
+                None => ty,
+            };
+
+            let mut variant: String = type_ident(&element.name);
+
+            while variants.iter().any(|(_, v, _)| *v == variant) {
+                variant.push('_');
+            }
+
+            variants.push((element, variant, ty));
+        }
+
+        doc(out, "", &schema.description);
+
+        writeln!(out, "#[derive(Debug, Clone)]\npub enum {} {{", ident).unwrap();
+
+        for (element, variant, ty) in variants.iter() {
+            doc(out, "    ", &element.description);
+            writeln!(out, "    {}({}),", variant, ty).unwrap();
+        }
+
+        writeln!(out, "}}").unwrap();
+
+        if let Some((_, variant, _)) = variants.first() {
+            writeln!(
+                out,
+                "\nimpl Default for {} {{\n    fn default() -> Self {{\n        {}::{}(Default::default())\n    }}\n}}",
+                ident, ident, variant
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "\nimpl {} for {} {{", TO_ELEMENT, ident).unwrap();
+        writeln!(
+            out,
+            "    fn to_element(&self, element: &mut {}) -> Result<(), {}> {{",
+            ELEMENT, ERROR
+        )
+        .unwrap();
+        writeln!(out, "        match self {{").unwrap();
+
+        for (element, variant, _) in variants.iter() {
+            writeln!(out, "            {}::{}(value) => {{", ident, variant).unwrap();
+            writeln!(
+                out,
+                "                let mut choice: {} = element.set_choice({:?})?;\n",
+                ELEMENT, element.name
+            )
+            .unwrap();
+
+            let complex: bool = element.datatype.is_complex();
+
+            if element.is_array() {
+                writeln!(out, "                for value in value.iter() {{").unwrap();
+
+                if complex {
+                    writeln!(
+                        out,
+                        "                    {}::to_element(value, &mut choice.append_element()?)?;",
+                        TO_ELEMENT
+                    )
+                    .unwrap();
+                } else {
+                    writeln!(
+                        out,
+                        "                    choice.append({})?;",
+                        self.value(element, "value", true)
+                    )
+                    .unwrap();
+                }
+
+                writeln!(out, "                }}\n\n                Ok(())").unwrap();
+            } else if complex {
+                writeln!(
+                    out,
+                    "                {}::to_element(value, &mut choice)",
+                    TO_ELEMENT
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    out,
+                    "                choice.set_at(0, {})",
+                    self.value(element, "value", true)
+                )
+                .unwrap();
+            }
+
+            writeln!(out, "            }}").unwrap();
+        }
+
+        writeln!(out, "        }}\n    }}\n}}").unwrap();
+
+        writeln!(out, "\nimpl {} for {} {{", GET_VALUE, ident).unwrap();
+        writeln!(
+            out,
+            "    fn get_at(element: &{}, index: usize) -> Option<Self> {{",
+            ELEMENT
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        let element: {} = element.get_at(index)?;\n        let choice: {} = element.get_choice()?;\n",
+            ELEMENT, ELEMENT
+        )
+        .unwrap();
+        writeln!(out, "        match &choice.string_name()[..] {{").unwrap();
+
+        for (element, variant, _) in variants.iter() {
+            writeln!(
+                out,
+                "            {:?} => Some({}::{}(choice.value()?)),",
+                element.name, ident, variant
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "            _ => None,\n        }}\n    }}\n}}").unwrap();
+    }
+
+    fn enumeration(&self, out: &mut String, schema: &TypeSchema) {
+        out.push('\n');
+
+        let ident: String = type_ident(&schema.name);
+        let mut variants: Vec<(String, &str)> = Vec::new();
+
+        for constant in schema.constants.iter() {
+            let mut variant: String = type_ident(constant);
+
+            while variants.iter().any(|(v, _)| *v == variant) {
+                variant.push('_');
+            }
+
+            variants.push((variant, constant));
+        }
+
+        doc(out, "", &schema.description);
+
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]\npub enum {} {{",
+            ident
+        )
+        .unwrap();
+
+        for (i, (variant, _)) in variants.iter().enumerate() {
+            if i == 0 {
+                writeln!(out, "    #[default]").unwrap();
+            }
+
+            writeln!(out, "    {},", variant).unwrap();
+        }
+
+        writeln!(
+            out,
+            "}}\n\nimpl {} {{\n    pub fn as_str(self) -> &'static str {{\n        match self {{",
+            ident
+        )
+        .unwrap();
+
+        for (variant, constant) in variants.iter() {
+            writeln!(out, "            {}::{} => {:?},", ident, variant, constant).unwrap();
+        }
+
+        writeln!(out, "        }}\n    }}\n}}").unwrap();
+
+        writeln!(
+            out,
+            "\nimpl ::std::str::FromStr for {} {{\n    type Err = {};\n\n    fn from_str(s: &str) -> Result<Self, {}> {{\n        match s {{",
+            ident, ERROR, ERROR
+        )
+        .unwrap();
+
+        for (variant, constant) in variants.iter() {
+            writeln!(
+                out,
+                "            {:?} => Ok({}::{}),",
+                constant, ident, variant
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            out,
+            "            _ => Err({}::NotFound(s.to_owned())),\n        }}\n    }}\n}}",
+            ERROR
+        )
+        .unwrap();
+
+        writeln!(
+            out,
+            "\nimpl {} for {} {{\n    fn get_at(element: &{}, index: usize) -> Option<Self> {{\n        element.get_at::<String>(index)?.parse().ok()\n    }}\n}}",
+            GET_VALUE, ident, ELEMENT
+        )
+        .unwrap();
+    }
+}
+
+fn doc(out: &mut String, indent: &str, description: &str) {
+    for line in description.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        writeln!(out, "{}/// {}", indent, line).unwrap();
+    }
+}
+
+pub fn type_ident(name: &str) -> String {
+    let mut ident: String = String::new();
+
+    for part in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let upper: bool = part.chars().all(|c| !c.is_ascii_lowercase());
+        let mut chars = part.chars();
+
+        if let Some(first) = chars.next() {
+            ident.push(first.to_ascii_uppercase());
+
+            if upper {
+                ident.extend(chars.map(|c| c.to_ascii_lowercase()));
+            } else {
+                ident.extend(chars);
+            }
+        }
+    }
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, 'V');
+    }
+
+    if RESERVED_TYPES.contains(&&ident[..]) {
+        ident.push_str("Type");
+    }
+
+    ident
+}
+
+pub fn field_ident(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut ident: String = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !ident.is_empty() && !ident.ends_with('_') {
+                ident.push('_');
+            }
+
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && i > 0 && !ident.ends_with('_') {
+            let previous: char = chars[i - 1];
+            let next: Option<&char> = chars.get(i + 1);
+
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()))
+            {
+                ident.push('_');
+            }
+        }
+
+        ident.push(c.to_ascii_lowercase());
+    }
+
+    let ident: &str = ident.trim_end_matches('_');
+
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", ident)
+    } else if matches!(ident, "self" | "super" | "crate") {
+        format!("{}_", ident)
+    } else if KEYWORDS.contains(&ident) {
+        format!("r#{}", ident)
+    } else {
+        ident.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::OperationSchema;
+
+    fn element(name: &str, datatype: DataType, type_name: Option<&str>) -> ElementSchema {
+        ElementSchema {
+            name: name.to_owned(),
+            description: String::new(),
+            datatype,
+            type_name: type_name.map(str::to_owned),
+            min_values: 1,
+            max_values: Some(1),
+        }
+    }
+
+    fn schema() -> ServiceSchema {
+        let mut securities: ElementSchema = element("securities", DataType::String, None);
+        securities.max_values = None;
+
+        let mut adjustment: ElementSchema = element(
+            "periodicityAdjustment",
+            DataType::Enumeration,
+            Some("PeriodicityAdjustment"),
+        );
+        adjustment.min_values = 0;
+
+        let mut schema: ServiceSchema = ServiceSchema {
+            name: "//blp/refdata".to_owned(),
+            operations: vec![OperationSchema {
+                name: "HistoricalDataRequest".to_owned(),
+                description: String::new(),
+                request: element(
+                    "HistoricalDataRequest",
+                    DataType::Sequence,
+                    Some("HistoricalDataRequest"),
+                ),
+                responses: Vec::new(),
+            }],
+            ..ServiceSchema::default()
+        };
+
+        schema.types.insert(
+            "HistoricalDataRequest".to_owned(),
+            TypeSchema {
+                name: "HistoricalDataRequest".to_owned(),
+                description: "Request historical data".to_owned(),
+                datatype: DataType::Sequence,
+                elements: vec![
+                    securities,
+                    element("startDate", DataType::String, None),
+                    adjustment,
+                    element("returnEids", DataType::Bool, None),
+                ],
+                constants: Vec::new(),
+            },
+        );
+
+        schema.types.insert(
+            "PeriodicityAdjustment".to_owned(),
+            TypeSchema {
+                name: "PeriodicityAdjustment".to_owned(),
+                description: String::new(),
+                datatype: DataType::Enumeration,
+                elements: Vec::new(),
+                constants: vec!["ACTUAL".to_owned(), "CALENDAR".to_owned()],
+            },
+        );
+
+        schema
+    }
+
+    #[test]
+    fn identifiers() {
+        assert_eq!(field_ident("returnEids"), "return_eids");
+        assert_eq!(field_ident("useUTCTime"), "use_utc_time");
+        assert_eq!(field_ident("type"), "r#type");
+        assert_eq!(field_ident("Declared Date"), "declared_date");
+        assert_eq!(type_ident("ACTUAL"), "Actual");
+        assert_eq!(type_ident("MONTHLY_AVG"), "MonthlyAvg");
+        assert_eq!(type_ident("1BF"), "V1bf");
+        assert_eq!(type_ident("securityData"), "SecurityData");
+    }
+
+    #[test]
+    fn generate_request() {
+        let code: String = Generator::new(&schema()).generate();
+
+        assert!(code.contains("pub struct HistoricalDataRequest {"));
+        assert!(code.contains("pub securities: Vec<String>,"));
+        assert!(code.contains("pub periodicity_adjustment: Option<PeriodicityAdjustment>,"));
+        assert!(code.contains("pub fn with_periodicity_adjustment(mut self, periodicity_adjustment: PeriodicityAdjustment) -> Self {"));
+        assert!(code.contains("element.set(\"periodicityAdjustment\", value.as_str())?;"));
+        assert!(code.contains("element.set(\"returnEids\", self.return_eids)?;"));
+        assert!(code.contains("    #[default]\n    Actual,"));
+        assert!(code.contains("const OPERATION: &'static str = \"HistoricalDataRequest\";"));
+    }
+
+    #[test]
+    fn filter_operations() {
+        let code: String = Generator::new(&schema())
+            .with_operations(["ReferenceDataRequest"])
+            .generate();
+
+        assert!(!code.contains("HistoricalDataRequest {"));
+    }
+}
@@ -0,0 +1,17 @@
+mod generator;
+#[cfg(feature = "live")]
+pub mod live;
+pub mod model;
+
+pub use generator::{field_ident, type_ident, Generator};
+pub use model::ServiceSchema;
+
+use std::path::Path;
+
+pub fn build<P: AsRef<Path>, Q: AsRef<Path>>(schema: P, out: Q) -> std::io::Result<()> {
+    println!("cargo:rerun-if-changed={}", schema.as_ref().display());
+
+    let schema: ServiceSchema = ServiceSchema::load(schema)?;
+
+    std::fs::write(out, Generator::new(&schema).generate())
+}
@@ -0,0 +1,152 @@
+use crate::model::{DataType, ElementSchema, OperationSchema, ServiceSchema, TypeSchema};
+use bloomberg::{
+    element,
+    schema::{SchemaElementDefinition, SchemaTypeDefinition},
+    service::Service,
+    Error,
+};
+use std::collections::BTreeMap;
+
+pub fn capture(service: &Service) -> Result<ServiceSchema, Error> {
+    let mut types: BTreeMap<String, TypeSchema> = BTreeMap::new();
+    let mut operations: Vec<OperationSchema> = Vec::new();
+
+    for operation in service.operations() {
+        let name: String = operation.name();
+
+        let request: ElementSchema = element(&mut types, &operation.request_definition()?, &name);
+
+        let responses: Vec<ElementSchema> = operation
+            .response_definitions()
+            .map(|definition| element(&mut types, &definition, &name))
+            .collect();
+
+        operations.push(OperationSchema {
+            name,
+            description: operation.description(),
+            request,
+            responses,
+        });
+    }
+
+    let events: Vec<ElementSchema> = service
+        .event_definitions()
+        .map(|definition| element(&mut types, &definition, ""))
+        .collect();
+
+    Ok(ServiceSchema {
+        name: service.name(),
+        description: service.description(),
+        operations,
+        events,
+        types,
+    })
+}
+
+fn element(
+    types: &mut BTreeMap<String, TypeSchema>,
+    definition: &SchemaElementDefinition,
+    scope: &str,
+) -> ElementSchema {
+    let type_definition: SchemaTypeDefinition = definition.type_definition();
+    let name: String = definition.string_name();
+
+    let datatype: DataType = if type_definition.is_enumeration() {
+        DataType::Enumeration
+    } else {
+        datatype(type_definition.datatype())
+    };
+
+    let type_name: Option<String> = if datatype.is_complex() || datatype == DataType::Enumeration {
+        Some(register(types, &type_definition, datatype, scope, &name))
+    } else {
+        None
+    };
+
+    ElementSchema {
+        name,
+        description: definition.description(),
+        datatype,
+        type_name,
+        min_values: definition.min_values(),
+        max_values: definition.max_values(),
+    }
+}
+
+fn register(
+    types: &mut BTreeMap<String, TypeSchema>,
+    type_definition: &SchemaTypeDefinition,
+    datatype: DataType,
+    scope: &str,
+    element_name: &str,
+) -> String {
+    let mut name: String = type_definition.string_name();
+
+    // Anonymous types are only unique within their parent, qualify them with its path.
+    if name.is_empty() {
+        name = if scope.is_empty() {
+            element_name.to_owned()
+        } else {
+            format!("{}.{}", scope, element_name)
+        };
+    }
+
+    if types.contains_key(&name) {
+        return name;
+    }
+
+    let constants: Vec<String> = type_definition
+        .enumeration()
+        .map(|constants| {
+            constants
+                .iter()
+                .map(|c| c.as_string().unwrap_or_else(|| c.string_name()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    types.insert(
+        name.clone(),
+        TypeSchema {
+            name: name.clone(),
+            description: type_definition.description(),
+            datatype,
+            elements: Vec::new(),
+            constants,
+        },
+    );
+
+    let elements: Vec<ElementSchema> = type_definition
+        .element_definitions()
+        .map(|definition| element(types, &definition, &name))
+        .collect();
+
+    if let Some(schema) = types.get_mut(&name) {
+        schema.elements = elements;
+    }
+
+    name
+}
+
+fn datatype(datatype: element::DataType) -> DataType {
+    match datatype {
+        element::DataType::Bool => DataType::Bool,
+        element::DataType::Char => DataType::Char,
+        element::DataType::Byte => DataType::Byte,
+        element::DataType::Int32 => DataType::Int32,
+        element::DataType::Int64 => DataType::Int64,
+        element::DataType::Float32 => DataType::Float32,
+        element::DataType::Float64 => DataType::Float64,
+        element::DataType::String => DataType::String,
+        element::DataType::ByteArray => DataType::ByteArray,
+        element::DataType::Date => DataType::Date,
+        element::DataType::Time => DataType::Time,
+        element::DataType::Decimal => DataType::Decimal,
+        element::DataType::Datetime => DataType::Datetime,
+        element::DataType::Enumeration => DataType::Enumeration,
+        element::DataType::Sequence => DataType::Sequence,
+        element::DataType::Choice => DataType::Choice,
+        element::DataType::CorrelationId => DataType::CorrelationId,
+        element::DataType::Unknown => DataType::Unknown,
+    }
+}
@@ -0,0 +1,67 @@
+use bloomberg_codegen::{Generator, ServiceSchema};
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+    bloomberg-codegen generate <schema.json> [<output.rs>] [<operation>...]
+    bloomberg-codegen dump <service> <schema.json>";
+
+fn generate(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let schema: ServiceSchema = ServiceSchema::load(args.first().ok_or(USAGE)?)?;
+    let mut generator: Generator = Generator::new(&schema);
+
+    if args.len() > 2 {
+        generator = generator.with_operations(args[2..].iter().cloned());
+    }
+
+    let code: String = generator.generate();
+
+    match args.get(1) {
+        Some(path) if path != "-" => std::fs::write(path, code)?,
+        _ => print!("{}", code),
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "live")]
+fn dump(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    use bloomberg::{service::Service, SessionSync};
+
+    let service_name: &String = args.first().ok_or(USAGE)?;
+    let path: &String = args.get(1).ok_or(USAGE)?;
+
+    let mut session: SessionSync = SessionSync::from_options(Default::default());
+
+    session.start()?;
+    session.open_service(service_name)?;
+
+    let service: Service = session.get_service(service_name)?;
+
+    bloomberg_codegen::live::capture(&service)?.save(path)?;
+
+    Ok(())
+}
+
+#[cfg(not(feature = "live"))]
+fn dump(_args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    Err("`dump` requires the `live` feature...".into())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let res = match args.first().map(|a| &a[..]) {
+        Some("generate") => generate(&args[1..]),
+        Some("dump") => dump(&args[1..]),
+        _ => Err(USAGE.into()),
+    };
+
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DataType {
+    Bool,
+    Char,
+    Byte,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    String,
+    ByteArray,
+    Date,
+    Time,
+    Decimal,
+    Datetime,
+    Enumeration,
+    Sequence,
+    Choice,
+    CorrelationId,
+    Unknown,
+}
+
+impl DataType {
+    pub fn is_complex(self) -> bool {
+        matches!(self, DataType::Sequence | DataType::Choice)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServiceSchema {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub operations: Vec<OperationSchema>,
+    #[serde(default)]
+    pub events: Vec<ElementSchema>,
+    #[serde(default)]
+    pub types: BTreeMap<String, TypeSchema>,
+}
+
+impl ServiceSchema {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> std::io::Result<Self> {
+        let json: String = std::fs::read_to_string(path)?;
+
+        Ok(ServiceSchema::from_json(&json)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json()?)
+    }
+
+    pub fn operation(&self, name: &str) -> Option<&OperationSchema> {
+        self.operations.iter().find(|o| o.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationSchema {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub request: ElementSchema,
+    #[serde(default)]
+    pub responses: Vec<ElementSchema>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElementSchema {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub datatype: DataType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    #[serde(default)]
+    pub min_values: usize,
+    #[serde(default)]
+    pub max_values: Option<usize>,
+}
+
+impl ElementSchema {
+    pub fn is_optional(&self) -> bool {
+        self.min_values == 0
+    }
+
+    pub fn is_array(&self) -> bool {
+        self.max_values != Some(1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeSchema {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub datatype: DataType,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub elements: Vec<ElementSchema>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constants: Vec<String>,
+}
@@ -0,0 +1,293 @@
+// Generated by bloomberg-codegen from `//blp/refdata`, do not edit.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PeriodicityAdjustment {
+    #[default]
+    Actual,
+    Calendar,
+    Fiscal,
+}
+
+impl PeriodicityAdjustment {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PeriodicityAdjustment::Actual => "ACTUAL",
+            PeriodicityAdjustment::Calendar => "CALENDAR",
+            PeriodicityAdjustment::Fiscal => "FISCAL",
+        }
+    }
+}
+
+impl ::std::str::FromStr for PeriodicityAdjustment {
+    type Err = ::bloomberg::Error;
+
+    fn from_str(s: &str) -> Result<Self, ::bloomberg::Error> {
+        match s {
+            "ACTUAL" => Ok(PeriodicityAdjustment::Actual),
+            "CALENDAR" => Ok(PeriodicityAdjustment::Calendar),
+            "FISCAL" => Ok(PeriodicityAdjustment::Fiscal),
+            _ => Err(::bloomberg::Error::NotFound(s.to_owned())),
+        }
+    }
+}
+
+impl ::bloomberg::element::GetValue for PeriodicityAdjustment {
+    fn get_at(element: &::bloomberg::element::Element, index: usize) -> Option<Self> {
+        element.get_at::<String>(index)?.parse().ok()
+    }
+}
+
+/// Request reference data
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceDataRequest {
+    pub securities: Vec<String>,
+    pub fields: Vec<String>,
+    pub overrides: Vec<ReferenceDataRequestOverrides>,
+    pub periodicity_adjustment: Option<PeriodicityAdjustment>,
+    pub return_eids: Option<bool>,
+    pub start_date: Option<::bloomberg::chrono::NaiveDate>,
+    pub r#type: i32,
+}
+
+impl ReferenceDataRequest {
+    pub fn new() -> Self {
+        ReferenceDataRequest::default()
+    }
+
+    pub fn with_securities(mut self, securities: Vec<String>) -> Self {
+        self.securities = securities;
+        self
+    }
+
+    pub fn with_fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    pub fn with_overrides(mut self, overrides: Vec<ReferenceDataRequestOverrides>) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    pub fn with_periodicity_adjustment(mut self, periodicity_adjustment: PeriodicityAdjustment) -> Self {
+        self.periodicity_adjustment = Some(periodicity_adjustment);
+        self
+    }
+
+    pub fn with_return_eids(mut self, return_eids: bool) -> Self {
+        self.return_eids = Some(return_eids);
+        self
+    }
+
+    pub fn with_start_date(mut self, start_date: ::bloomberg::chrono::NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn with_type(mut self, r#type: i32) -> Self {
+        self.r#type = r#type;
+        self
+    }
+}
+
+impl ::bloomberg::element::ToElement for ReferenceDataRequest {
+    fn to_element(&self, element: &mut ::bloomberg::element::Element) -> Result<(), ::bloomberg::Error> {
+        let mut array: ::bloomberg::element::Element = element.get_element("securities").ok_or_else(|| ::bloomberg::Error::NotFound("securities".to_owned()))?;
+
+        for value in self.securities.iter() {
+            array.append(&value[..])?;
+        }
+
+        let mut array: ::bloomberg::element::Element = element.get_element("fields").ok_or_else(|| ::bloomberg::Error::NotFound("fields".to_owned()))?;
+
+        for value in self.fields.iter() {
+            array.append(&value[..])?;
+        }
+
+        let mut array: ::bloomberg::element::Element = element.get_element("overrides").ok_or_else(|| ::bloomberg::Error::NotFound("overrides".to_owned()))?;
+
+        for value in self.overrides.iter() {
+            ::bloomberg::element::ToElement::to_element(value, &mut array.append_element()?)?;
+        }
+
+        if let Some(value) = self.periodicity_adjustment.as_ref() {
+            element.set("periodicityAdjustment", value.as_str())?;
+        }
+
+        if let Some(value) = self.return_eids.as_ref() {
+            element.set("returnEids", *value)?;
+        }
+
+        if let Some(value) = self.start_date.as_ref() {
+            element.set("startDate", &::bloomberg::datetime::Datetime::from(*value))?;
+        }
+
+        element.set("type", self.r#type)?;
+
+        Ok(())
+    }
+}
+
+impl ::bloomberg::element::GetValue for ReferenceDataRequest {
+    fn get_at(element: &::bloomberg::element::Element, index: usize) -> Option<Self> {
+        let element: ::bloomberg::element::Element = element.get_at(index)?;
+
+        Some(ReferenceDataRequest {
+            securities: element.get_element("securities").and_then(|e| e.value()).unwrap_or_default(),
+            fields: element.get_element("fields").and_then(|e| e.value()).unwrap_or_default(),
+            overrides: element.get_element("overrides").and_then(|e| e.value()).unwrap_or_default(),
+            periodicity_adjustment: element.get_element("periodicityAdjustment").and_then(|e| e.value()),
+            return_eids: element.get_element("returnEids").and_then(|e| e.value()),
+            start_date: element.get_element("startDate").and_then(|e| e.value()),
+            r#type: element.get_element("type")?.value()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ReferenceDataRequestReferenceDataResponse {
+    ResponseError(String),
+    SecurityData(Vec<ReferenceDataRequestReferenceDataResponseSecurityData>),
+}
+
+impl Default for ReferenceDataRequestReferenceDataResponse {
+    fn default() -> Self {
+        ReferenceDataRequestReferenceDataResponse::ResponseError(Default::default())
+    }
+}
+
+impl ::bloomberg::element::ToElement for ReferenceDataRequestReferenceDataResponse {
+    fn to_element(&self, element: &mut ::bloomberg::element::Element) -> Result<(), ::bloomberg::Error> {
+        match self {
+            ReferenceDataRequestReferenceDataResponse::ResponseError(value) => {
+                let mut choice: ::bloomberg::element::Element = element.set_choice("responseError")?;
+
+                choice.set_at(0, &value[..])
+            }
+            ReferenceDataRequestReferenceDataResponse::SecurityData(value) => {
+                let mut choice: ::bloomberg::element::Element = element.set_choice("securityData")?;
+
+                for value in value.iter() {
+                    ::bloomberg::element::ToElement::to_element(value, &mut choice.append_element()?)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ::bloomberg::element::GetValue for ReferenceDataRequestReferenceDataResponse {
+    fn get_at(element: &::bloomberg::element::Element, index: usize) -> Option<Self> {
+        let element: ::bloomberg::element::Element = element.get_at(index)?;
+        let choice: ::bloomberg::element::Element = element.get_choice()?;
+
+        match &choice.string_name()[..] {
+            "responseError" => Some(ReferenceDataRequestReferenceDataResponse::ResponseError(choice.value()?)),
+            "securityData" => Some(ReferenceDataRequestReferenceDataResponse::SecurityData(choice.value()?)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceDataRequestReferenceDataResponseSecurityData {
+    pub security: String,
+    pub sequence_number: i32,
+    pub time: Option<::bloomberg::datetime::Datetime>,
+}
+
+impl ReferenceDataRequestReferenceDataResponseSecurityData {
+    pub fn new() -> Self {
+        ReferenceDataRequestReferenceDataResponseSecurityData::default()
+    }
+
+    pub fn with_security(mut self, security: String) -> Self {
+        self.security = security;
+        self
+    }
+
+    pub fn with_sequence_number(mut self, sequence_number: i32) -> Self {
+        self.sequence_number = sequence_number;
+        self
+    }
+
+    pub fn with_time(mut self, time: ::bloomberg::datetime::Datetime) -> Self {
+        self.time = Some(time);
+        self
+    }
+}
+
+impl ::bloomberg::element::ToElement for ReferenceDataRequestReferenceDataResponseSecurityData {
+    fn to_element(&self, element: &mut ::bloomberg::element::Element) -> Result<(), ::bloomberg::Error> {
+        element.set("security", &self.security[..])?;
+
+        element.set("sequenceNumber", self.sequence_number)?;
+
+        if let Some(value) = self.time.as_ref() {
+            element.set("time", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ::bloomberg::element::GetValue for ReferenceDataRequestReferenceDataResponseSecurityData {
+    fn get_at(element: &::bloomberg::element::Element, index: usize) -> Option<Self> {
+        let element: ::bloomberg::element::Element = element.get_at(index)?;
+
+        Some(ReferenceDataRequestReferenceDataResponseSecurityData {
+            security: element.get_element("security")?.value()?,
+            sequence_number: element.get_element("sequenceNumber")?.value()?,
+            time: element.get_element("time").and_then(|e| e.value()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceDataRequestOverrides {
+    pub field_id: String,
+    pub value: String,
+}
+
+impl ReferenceDataRequestOverrides {
+    pub fn new() -> Self {
+        ReferenceDataRequestOverrides::default()
+    }
+
+    pub fn with_field_id(mut self, field_id: String) -> Self {
+        self.field_id = field_id;
+        self
+    }
+
+    pub fn with_value(mut self, value: String) -> Self {
+        self.value = value;
+        self
+    }
+}
+
+impl ::bloomberg::element::ToElement for ReferenceDataRequestOverrides {
+    fn to_element(&self, element: &mut ::bloomberg::element::Element) -> Result<(), ::bloomberg::Error> {
+        element.set("fieldId", &self.field_id[..])?;
+
+        element.set("value", &self.value[..])?;
+
+        Ok(())
+    }
+}
+
+impl ::bloomberg::element::GetValue for ReferenceDataRequestOverrides {
+    fn get_at(element: &::bloomberg::element::Element, index: usize) -> Option<Self> {
+        let element: ::bloomberg::element::Element = element.get_at(index)?;
+
+        Some(ReferenceDataRequestOverrides {
+            field_id: element.get_element("fieldId")?.value()?,
+            value: element.get_element("value")?.value()?,
+        })
+    }
+}
+
+impl ::bloomberg::request::Operation for ReferenceDataRequest {
+    const SERVICE: &'static str = "//blp/refdata";
+    const OPERATION: &'static str = "ReferenceDataRequest";
+}
@@ -0,0 +1,49 @@
+use bloomberg::{element::GetValue, element::ToElement, request::Operation};
+use bloomberg_codegen::{Generator, ServiceSchema};
+
+#[allow(dead_code)]
+mod refdata {
+    include!("fixtures/refdata.rs");
+}
+
+use refdata::{
+    PeriodicityAdjustment, ReferenceDataRequest, ReferenceDataRequestOverrides,
+    ReferenceDataRequestReferenceDataResponse,
+};
+
+fn assert_element<T: ToElement + GetValue + Default>() {}
+
+#[test]
+fn fixture_is_current() {
+    let schema: ServiceSchema = ServiceSchema::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/refdata.json"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        Generator::new(&schema).generate(),
+        include_str!("fixtures/refdata.rs")
+    );
+}
+
+#[test]
+fn generated_code() {
+    let request: ReferenceDataRequest = ReferenceDataRequest::new()
+        .with_securities(vec!["IBM US Equity".to_owned()])
+        .with_overrides(vec![ReferenceDataRequestOverrides::new()
+            .with_field_id("EQY_FUND_CRNCY".to_owned())
+            .with_value("USD".to_owned())])
+        .with_periodicity_adjustment("CALENDAR".parse().unwrap());
+
+    assert_eq!(
+        request.periodicity_adjustment,
+        Some(PeriodicityAdjustment::Calendar)
+    );
+    assert_eq!(request.overrides[0].field_id, "EQY_FUND_CRNCY");
+    assert_eq!(ReferenceDataRequest::SERVICE, "//blp/refdata");
+    assert_eq!(ReferenceDataRequest::OPERATION, "ReferenceDataRequest");
+
+    assert_element::<ReferenceDataRequest>();
+    assert_element::<ReferenceDataRequestReferenceDataResponse>();
+}
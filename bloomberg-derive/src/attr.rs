@@ -0,0 +1,92 @@
+use syn::{Attribute, ExprPath, Field, LitStr};
+
+const ATTRIBUTE: &str = "bbg";
+
+pub enum DefaultValue {
+    Trait,
+    Path(ExprPath),
+}
+
+pub struct FieldAttr {
+    pub field: Option<String>,
+    pub skip: bool,
+    pub default: Option<DefaultValue>,
+}
+
+impl FieldAttr {
+    pub fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attr: FieldAttr = FieldAttr {
+            field: None,
+            skip: false,
+            default: None,
+        };
+
+        for attribute in bbg_attributes(&field.attrs) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("field") {
+                    let value: LitStr = meta.value()?.parse()?;
+
+                    if value.value().is_empty() {
+                        return Err(meta.error("`field` cannot be empty"));
+                    }
+
+                    attr.field = Some(value.value());
+                } else if meta.path.is_ident("skip") {
+                    attr.skip = true;
+                } else if meta.path.is_ident("default") {
+                    attr.default = if meta.input.peek(syn::Token![=]) {
+                        let value: LitStr = meta.value()?.parse()?;
+
+                        Some(DefaultValue::Path(value.parse()?))
+                    } else {
+                        Some(DefaultValue::Trait)
+                    };
+                } else {
+                    return Err(meta.error("unsupported `bbg` field attribute"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(attr)
+    }
+}
+
+#[derive(Default)]
+pub struct StructAttr {
+    pub overrides: Vec<(String, String)>,
+}
+
+impl StructAttr {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attr: StructAttr = StructAttr::default();
+
+        for attribute in bbg_attributes(attrs) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("overrides") {
+                    meta.parse_nested_meta(|inner| {
+                        let field: String = match inner.path.get_ident() {
+                            Some(ident) => ident.to_string(),
+                            None => return Err(inner.error("expected a Bloomberg field name")),
+                        };
+
+                        let value: LitStr = inner.value()?.parse()?;
+
+                        attr.overrides.push((field, value.value()));
+
+                        Ok(())
+                    })
+                } else {
+                    Err(meta.error("unsupported `bbg` struct attribute"))
+                }
+            })?;
+        }
+
+        Ok(attr)
+    }
+}
+
+fn bbg_attributes(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|a| a.path().is_ident(ATTRIBUTE))
+}
@@ -0,0 +1,112 @@
+use crate::ref_data::{assertions, fallback, fields, from_value, RefDataField};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Ident};
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let columns: Vec<RefDataField> = fields(&input, "FromElement")?;
+
+    let ident: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let assertions = assertions(&columns);
+
+    let arms = columns.iter().map(|f| {
+        let RefDataField {
+            ident,
+            name,
+            default,
+            ..
+        } = f;
+
+        let fallback: TokenStream = fallback(&quote! { row.#ident }, default);
+
+        quote! {
+            if key == ::bloomberg::ref_data::column_key(#name) {
+                match column.get_at(0) {
+                    ::std::option::Option::Some(value) => row.#ident = value,
+                    ::std::option::Option::None => { #fallback }
+                }
+
+                continue;
+            }
+        }
+    });
+
+    let value_arms = columns.iter().map(|f| {
+        let RefDataField {
+            ident,
+            ty,
+            name,
+            default,
+        } = f;
+
+        let from_value: TokenStream = from_value(ty, &quote! { column });
+        let fallback: TokenStream = fallback(&quote! { row.#ident }, default);
+
+        quote! {
+            if key == ::bloomberg::ref_data::column_key(#name) {
+                match #from_value {
+                    ::std::option::Option::Some(value) => row.#ident = value,
+                    ::std::option::Option::None => { #fallback }
+                }
+
+                continue;
+            }
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            fn assert_get_value<T: ::bloomberg::element::GetValue>() {}
+
+            #[allow(dead_code)]
+            fn assert_fields #impl_generics () #where_clause {
+                #(#assertions)*
+            }
+
+            impl #impl_generics ::bloomberg::ref_data::FromElement for #ident #ty_generics #where_clause {
+                fn from_element(element: &::bloomberg::element::Element) -> ::std::option::Option<Self> {
+                    if !element.is_complex_type() {
+                        return ::std::option::Option::None;
+                    }
+
+                    let mut row: Self = ::std::default::Default::default();
+
+                    for column in element.elements() {
+                        let key: ::std::string::String =
+                            ::bloomberg::ref_data::column_key(&column.string_name());
+
+                        #(#arms)*
+                    }
+
+                    ::std::option::Option::Some(row)
+                }
+            }
+
+            impl #impl_generics ::bloomberg::element::GetValue for #ident #ty_generics #where_clause {
+                fn get_at(element: &::bloomberg::element::Element, index: usize) -> ::std::option::Option<Self> {
+                    let row: ::bloomberg::element::Element = element.get_at(index)?;
+
+                    <Self as ::bloomberg::ref_data::FromElement>::from_element(&row)
+                }
+            }
+
+            ::bloomberg::__cache_data! {
+                impl #impl_generics ::bloomberg::cache::FromValue for #ident #ty_generics #where_clause {
+                    fn from_value(value: &::bloomberg::serde_json::Value) -> ::std::option::Option<Self> {
+                        let mut row: Self = ::std::default::Default::default();
+
+                        for (name, column) in value.as_object()? {
+                            let key: ::std::string::String = ::bloomberg::ref_data::column_key(name);
+
+                            #(#value_arms)*
+                        }
+
+                        ::std::option::Option::Some(row)
+                    }
+                }
+            }
+        };
+    })
+}
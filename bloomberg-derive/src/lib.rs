@@ -0,0 +1,24 @@
+mod attr;
+mod from_element;
+mod ref_data;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+#[proc_macro_derive(RefData, attributes(bbg))]
+pub fn derive_ref_data(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    ref_data::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(FromElement, attributes(bbg))]
+pub fn derive_from_element(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    from_element::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,270 @@
+use crate::attr::{DefaultValue, FieldAttr, StructAttr};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, Ident, Type};
+
+pub struct RefDataField {
+    pub ident: Ident,
+    pub ty: Type,
+    pub name: String,
+    pub default: Option<DefaultValue>,
+}
+
+pub fn fields(input: &DeriveInput, derive: &str) -> syn::Result<Vec<RefDataField>> {
+    let fields: &Fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                format!("`{}` can only be derived for structs", derive),
+            ))
+        }
+    };
+
+    let named = match fields {
+        Fields::Named(named) => &named.named,
+        _ => {
+            return Err(syn::Error::new(
+                fields.span(),
+                format!(
+                    "`{}` can only be derived for structs with named fields",
+                    derive
+                ),
+            ))
+        }
+    };
+
+    let mut ref_data_fields: Vec<RefDataField> = Vec::with_capacity(named.len());
+
+    for field in named {
+        let attr: FieldAttr = FieldAttr::parse(field)?;
+
+        if attr.skip {
+            continue;
+        }
+
+        let ident: Ident = field.ident.clone().expect("named field");
+
+        let name: String = attr
+            .field
+            .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_uppercase());
+
+        if ref_data_fields.iter().any(|f| f.name == name) {
+            return Err(syn::Error::new(
+                field.span(),
+                format!("duplicate Bloomberg field `{}`", name),
+            ));
+        }
+
+        ref_data_fields.push(RefDataField {
+            ident,
+            ty: field.ty.clone(),
+            name,
+            default: attr.default,
+        });
+    }
+
+    Ok(ref_data_fields)
+}
+
+pub fn fallback(target: &TokenStream, default: &Option<DefaultValue>) -> TokenStream {
+    match default {
+        Some(DefaultValue::Trait) => quote! {
+            #target = ::std::default::Default::default();
+        },
+        Some(DefaultValue::Path(path)) => quote! {
+            #target = #path();
+        },
+        None => TokenStream::new(),
+    }
+}
+
+pub fn assertions<'a, I>(fields: I) -> impl Iterator<Item = TokenStream> + 'a
+where
+    I: IntoIterator<Item = &'a RefDataField>,
+    I::IntoIter: 'a,
+{
+    fields.into_iter().map(|f| {
+        let ty: &Type = &f.ty;
+
+        quote_spanned! {ty.span()=>
+            assert_get_value::<#ty>();
+        }
+    })
+}
+
+pub fn from_value(ty: &Type, value: &TokenStream) -> TokenStream {
+    quote_spanned! {ty.span()=>
+        <#ty as ::bloomberg::cache::FromValue>::from_value(#value)
+    }
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields: Vec<RefDataField> = fields(&input, "RefData")?;
+    let attr: StructAttr = StructAttr::parse(&input.attrs)?;
+
+    let ident: &Ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let names = fields.iter().map(|f| &f.name);
+
+    let overrides: TokenStream = if attr.overrides.is_empty() {
+        TokenStream::new()
+    } else {
+        let overrides = attr
+            .overrides
+            .iter()
+            .map(|(field, value)| quote! { (#field, #value) });
+
+        quote! {
+            const OVERRIDES: &'static [(&'static str, &'static str)] = &[#(#overrides),*];
+        }
+    };
+
+    let assertions = assertions(&fields);
+
+    let arms = fields.iter().map(|f| {
+        let RefDataField {
+            ident,
+            name,
+            default,
+            ..
+        } = f;
+
+        let fallback: TokenStream = fallback(&quote! { self.#ident }, default);
+
+        quote! {
+            #name => match element.get_at(0) {
+                ::std::option::Option::Some(value) => self.#ident = value,
+                ::std::option::Option::None => { #fallback }
+            },
+        }
+    });
+
+    let value_arms = fields.iter().map(|f| {
+        let RefDataField {
+            ident,
+            ty,
+            name,
+            default,
+        } = f;
+
+        let from_value: TokenStream = from_value(ty, &quote! { value });
+        let fallback: TokenStream = fallback(&quote! { self.#ident }, default);
+
+        quote! {
+            #name => match #from_value {
+                ::std::option::Option::Some(value) => self.#ident = value,
+                ::std::option::Option::None => { #fallback }
+            },
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            fn assert_get_value<T: ::bloomberg::element::GetValue>() {}
+
+            #[allow(dead_code)]
+            fn assert_fields #impl_generics () #where_clause {
+                #(#assertions)*
+            }
+
+            impl #impl_generics ::bloomberg::ref_data::RefData for #ident #ty_generics #where_clause {
+                const FIELDS: &'static [&'static str] = &[#(#names),*];
+
+                #overrides
+
+                fn on_field(&mut self, field: &str, element: &::bloomberg::element::Element) {
+                    match field {
+                        #(#arms)*
+                        _ => (),
+                    }
+                }
+            }
+
+            ::bloomberg::__cache_data! {
+                impl #impl_generics ::bloomberg::cache::CacheData for #ident #ty_generics #where_clause {
+                    fn on_value(&mut self, field: &str, value: &::bloomberg::serde_json::Value) {
+                        match field {
+                            #(#value_arms)*
+                            _ => (),
+                        }
+                    }
+                }
+            }
+        };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_names() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Price {
+                px_last: f64,
+                #[bbg(field = "NAME")]
+                security_name: String,
+                #[bbg(skip)]
+                cached: bool,
+                #[bbg(default)]
+                r#volume: Option<i64>,
+            }
+        };
+
+        let names: Vec<String> = fields(&input, "RefData")
+            .unwrap()
+            .into_iter()
+            .map(|f| f.name)
+            .collect();
+
+        assert_eq!(names, ["PX_LAST", "NAME", "VOLUME"]);
+    }
+
+    #[test]
+    fn struct_overrides() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[bbg(overrides(EQY_FUND_CRNCY = "USD", BEST_FPERIOD_OVERRIDE = "1BF"))]
+            struct Estimates {
+                best_eps: f64,
+            }
+        };
+
+        let attr: StructAttr = StructAttr::parse(&input.attrs).unwrap();
+
+        assert_eq!(
+            attr.overrides,
+            [
+                ("EQY_FUND_CRNCY".to_owned(), "USD".to_owned()),
+                ("BEST_FPERIOD_OVERRIDE".to_owned(), "1BF".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_field() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Price {
+                px_last: f64,
+                #[bbg(field = "PX_LAST")]
+                last: f64,
+            }
+        };
+
+        assert!(fields(&input, "RefData").is_err());
+    }
+
+    #[test]
+    fn unknown_attribute() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct Price {
+                #[bbg(rename = "PX_LAST")]
+                px_last: f64,
+            }
+        };
+
+        assert!(fields(&input, "RefData").is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Error;
+
+#[derive(Debug, Default)]
+pub struct Batch<T> {
+    pub data: T,
+    pub errors: Vec<Error>,
+}
+
+impl<T> Batch<T> {
+    pub fn new(data: T) -> Self {
+        Batch {
+            data,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn security_errors(&self) -> impl Iterator<Item = &Error> {
+        self.errors
+            .iter()
+            .filter(|e| matches!(e, Error::Security { .. }))
+    }
+
+    pub fn field_errors(&self) -> impl Iterator<Item = &Error> {
+        self.errors
+            .iter()
+            .filter(|e| matches!(e, Error::Field { .. }))
+    }
+
+    pub fn errors_for<'a>(&'a self, security: &'a str) -> impl Iterator<Item = &'a Error> {
+        self.errors.iter().filter(move |e| match e {
+            Error::Security { security: s, .. } | Error::Field { security: s, .. } => s == security,
+            _ => false,
+        })
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+
+    pub fn into_result(mut self) -> Result<T, Error> {
+        if self.errors.is_empty() {
+            Ok(self.data)
+        } else {
+            Err(self.errors.swap_remove(0))
+        }
+    }
+}
+
+impl<T> std::ops::Deref for Batch<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data
+    }
+}
+
+impl<T> std::ops::DerefMut for Batch<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn security(security: &str) -> Error {
+        Error::Security {
+            security: security.to_owned(),
+            category: "BAD_SEC".to_owned(),
+            sub_category: None,
+            message: "Unknown/Invalid security".to_owned(),
+        }
+    }
+
+    #[test]
+    fn batch_errors() {
+        let mut batch: Batch<Vec<i32>> = Batch::new(vec![1, 2]);
+
+        assert!(batch.is_complete());
+
+        batch.errors.push(security("XXX US Equity"));
+        batch.errors.push(Error::Field {
+            security: "IBM US Equity".to_owned(),
+            field: "PX_LASTT".to_owned(),
+            category: "BAD_FLD".to_owned(),
+            sub_category: None,
+            message: "Field not valid".to_owned(),
+        });
+
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.security_errors().count(), 1);
+        assert_eq!(batch.field_errors().count(), 1);
+        assert_eq!(batch.errors_for("IBM US Equity").count(), 1);
+        assert!(batch.into_result().is_err());
+    }
+}
@@ -422,9 +422,7 @@ fn bindgen_test_layout_blpapi_ManagedPtr_t_data_() {
         concat!("Alignment of ", stringify!(blpapi_ManagedPtr_t_data_))
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<blpapi_ManagedPtr_t_data_>())).intValue as *const _ as usize
-        },
+        ::std::mem::offset_of!(blpapi_ManagedPtr_t_data_, intValue),
         0usize,
         concat!(
             "Offset of field: ",
@@ -434,7 +432,7 @@ fn bindgen_test_layout_blpapi_ManagedPtr_t_data_() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_ManagedPtr_t_data_>())).ptr as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_ManagedPtr_t_data_, ptr),
         0usize,
         concat!(
             "Offset of field: ",
@@ -464,7 +462,7 @@ fn bindgen_test_layout_blpapi_ManagedPtr_t_() {
         concat!("Alignment of ", stringify!(blpapi_ManagedPtr_t_))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_ManagedPtr_t_>())).pointer as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_ManagedPtr_t_, pointer),
         0usize,
         concat!(
             "Offset of field: ",
@@ -474,7 +472,7 @@ fn bindgen_test_layout_blpapi_ManagedPtr_t_() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_ManagedPtr_t_>())).userData as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_ManagedPtr_t_, userData),
         8usize,
         concat!(
             "Offset of field: ",
@@ -484,7 +482,7 @@ fn bindgen_test_layout_blpapi_ManagedPtr_t_() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_ManagedPtr_t_>())).manager as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_ManagedPtr_t_, manager),
         40usize,
         concat!(
             "Offset of field: ",
@@ -526,10 +524,7 @@ fn bindgen_test_layout_blpapi_CorrelationId_t___bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<blpapi_CorrelationId_t___bindgen_ty_1>())).intValue as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(blpapi_CorrelationId_t___bindgen_ty_1, intValue),
         0usize,
         concat!(
             "Offset of field: ",
@@ -539,10 +534,7 @@ fn bindgen_test_layout_blpapi_CorrelationId_t___bindgen_ty_1() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<blpapi_CorrelationId_t___bindgen_ty_1>())).ptrValue as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(blpapi_CorrelationId_t___bindgen_ty_1, ptrValue),
         0usize,
         concat!(
             "Offset of field: ",
@@ -565,7 +557,7 @@ fn bindgen_test_layout_blpapi_CorrelationId_t_() {
         concat!("Alignment of ", stringify!(blpapi_CorrelationId_t_))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_CorrelationId_t_>())).value as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_CorrelationId_t_, value),
         8usize,
         concat!(
             "Offset of field: ",
@@ -698,7 +690,7 @@ fn bindgen_test_layout_blpapi_ErrorInfo() {
         concat!("Alignment of ", stringify!(blpapi_ErrorInfo))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_ErrorInfo>())).exceptionClass as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_ErrorInfo, exceptionClass),
         0usize,
         concat!(
             "Offset of field: ",
@@ -708,7 +700,7 @@ fn bindgen_test_layout_blpapi_ErrorInfo() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_ErrorInfo>())).description as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_ErrorInfo, description),
         4usize,
         concat!(
             "Offset of field: ",
@@ -750,7 +742,7 @@ fn bindgen_test_layout_blpapi_TimePoint() {
         concat!("Alignment of ", stringify!(blpapi_TimePoint))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_TimePoint>())).d_value as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_TimePoint, d_value),
         0usize,
         concat!(
             "Offset of field: ",
@@ -793,7 +785,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         concat!("Alignment of ", stringify!(blpapi_Datetime_tag))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).parts as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, parts),
         0usize,
         concat!(
             "Offset of field: ",
@@ -803,7 +795,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).hours as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, hours),
         1usize,
         concat!(
             "Offset of field: ",
@@ -813,7 +805,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).minutes as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, minutes),
         2usize,
         concat!(
             "Offset of field: ",
@@ -823,7 +815,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).seconds as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, seconds),
         3usize,
         concat!(
             "Offset of field: ",
@@ -833,9 +825,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<blpapi_Datetime_tag>())).milliSeconds as *const _ as usize
-        },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, milliSeconds),
         4usize,
         concat!(
             "Offset of field: ",
@@ -845,7 +835,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).month as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, month),
         6usize,
         concat!(
             "Offset of field: ",
@@ -855,7 +845,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).day as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, day),
         7usize,
         concat!(
             "Offset of field: ",
@@ -865,7 +855,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).year as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, year),
         8usize,
         concat!(
             "Offset of field: ",
@@ -875,7 +865,7 @@ fn bindgen_test_layout_blpapi_Datetime_tag() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<blpapi_Datetime_tag>())).offset as *const _ as usize },
+        ::std::mem::offset_of!(blpapi_Datetime_tag, offset),
         10usize,
         concat!(
             "Offset of field: ",
@@ -908,10 +898,7 @@ fn bindgen_test_layout_blpapi_HighPrecisionDatetime_tag() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<blpapi_HighPrecisionDatetime_tag>())).datetime as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(blpapi_HighPrecisionDatetime_tag, datetime),
         0usize,
         concat!(
             "Offset of field: ",
@@ -921,10 +908,7 @@ fn bindgen_test_layout_blpapi_HighPrecisionDatetime_tag() {
         )
     );
     assert_eq!(
-        unsafe {
-            &(*(::std::ptr::null::<blpapi_HighPrecisionDatetime_tag>())).picoseconds as *const _
-                as usize
-        },
+        ::std::mem::offset_of!(blpapi_HighPrecisionDatetime_tag, picoseconds),
         12usize,
         concat!(
             "Offset of field: ",
@@ -2542,6 +2526,17 @@ extern "C" {
         parameters: *mut blpapi_SessionOptions_t,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn blpapi_SessionOptions_setBandwidthSaveModeDisabled(
+        parameters: *mut blpapi_SessionOptions_t,
+        disableBandwidthSaveMode: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn blpapi_SessionOptions_bandwidthSaveModeDisabled(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> ::std::os::raw::c_int;
+}
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct blpapi_SubscriptionList {
@@ -3327,6 +3322,26 @@ extern "C" {
         formatter: *mut blpapi_EventFormatter_t,
     ) -> ::std::os::raw::c_int;
 }
+extern "C" {
+    pub fn blpapi_TestUtil_createEvent(
+        event: *mut *mut blpapi_Event_t,
+        eventType: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn blpapi_TestUtil_deserializeService(
+        schema: *const ::std::os::raw::c_char,
+        schemaLength: usize,
+        service: *mut *mut blpapi_Service_t,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn blpapi_TestUtil_serializeService(
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut ::std::os::raw::c_void,
+        service: *const blpapi_Service_t,
+    ) -> ::std::os::raw::c_int;
+}
 extern "C" {
     pub fn blpapi_DiagnosticsUtil_memoryInfo(
         buffer: *mut ::std::os::raw::c_char,
@@ -1,9 +1,7 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-const ENV_WARNING: &'static str = r#"Error while building blpapi-sys.
-
-    Cannot find 'BLPAPI_LIB' environment variable.
+const ENV_WARNING: &str = r#"Cannot find 'BLPAPI_LIB' environment variable, blpapi will not be linked.
 
     You can download blpapi binaries from bloomberg at:
     https://www.bloomberg.com/professional/support/api-library/
@@ -12,43 +10,147 @@ const ENV_WARNING: &'static str = r#"Error while building blpapi-sys.
     corresponding lib dir:
 
     - windows: <EXTRACT_PATH>\lib
-    - linux: <EXTRACT_PATH>/Linux"
+    - linux: <EXTRACT_PATH>/Linux
 "#;
 
+const BINDINGS_VERSION: [u32; 3] = [3, 12, 3];
+
 fn main() {
-    let lib_dir: String = if cfg!(feature = "bundled") {
-        let mut dir: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    println!("cargo:rerun-if-changed=src/wrapper.h");
+    println!("cargo:rerun-if-env-changed=BLPAPI_LIB");
+    println!("cargo:rerun-if-env-changed=BLPAPI_INCLUDE");
 
-        dir.pop();
-        dir.push("vendor");
+    let lib_dir: Option<PathBuf> = if cfg!(feature = "bundled") {
+        Some(vendor_dir())
+    } else {
+        std::env::var_os("BLPAPI_LIB").map(PathBuf::from)
+    };
 
-        for entry in fs::read_dir(dir.as_path()).expect("Failed to read `vendor/` dir...") {
-            let entry: fs::DirEntry = entry.expect("Failed to read entry in `vendor/` dir...");
-            let path: PathBuf = entry.path();
+    let include_dir: Option<PathBuf> = std::env::var_os("BLPAPI_INCLUDE")
+        .map(PathBuf::from)
+        .or_else(|| {
+            lib_dir
+                .as_deref()
+                .and_then(Path::parent)
+                .map(|root| root.join("include"))
+        });
 
-            if path.is_dir() {
-                let dir_name: std::borrow::Cow<str> =
-                    path.file_name().unwrap_or_default().to_string_lossy();
+    if let Some(include_dir) = include_dir.as_deref() {
+        check_version(include_dir);
+    }
 
-                if cfg!(windows) && dir_name.ends_with("windows") {
-                    dir.push(path);
-                    dir.push("lib");
+    #[cfg(feature = "bindgen")]
+    generate_bindings(include_dir.as_deref());
 
-                    break;
-                } else if cfg!(unix) && dir_name.ends_with("linux") {
-                    dir.push(path);
-                    dir.push("Linux");
+    if cfg!(feature = "dynamic") {
+        return;
+    }
 
-                    break;
-                }
+    match lib_dir {
+        Some(lib_dir) => {
+            println!("cargo:rustc-link-search={}", lib_dir.display());
+            println!("cargo:rustc-link-lib=blpapi3_64");
+        }
+        None => {
+            for line in ENV_WARNING.lines().filter(|line| !line.trim().is_empty()) {
+                println!("cargo:warning={}", line);
             }
         }
+    }
+}
 
-        dir.into_os_string().into_string().unwrap()
-    } else {
-        std::env::var("BLPAPI_LIB").expect(ENV_WARNING)
+fn vendor_dir() -> PathBuf {
+    let mut dir: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    dir.pop();
+    dir.push("vendor");
+
+    for entry in fs::read_dir(dir.as_path()).expect("Failed to read `vendor/` dir...") {
+        let entry: fs::DirEntry = entry.expect("Failed to read entry in `vendor/` dir...");
+        let path: PathBuf = entry.path();
+
+        if path.is_dir() {
+            let dir_name: std::borrow::Cow<str> =
+                path.file_name().unwrap_or_default().to_string_lossy();
+
+            if cfg!(windows) && dir_name.ends_with("windows") {
+                dir.push(path);
+                dir.push("lib");
+
+                break;
+            } else if cfg!(unix) && dir_name.ends_with("linux") {
+                dir.push(path);
+                dir.push("Linux");
+
+                break;
+            }
+        }
+    }
+
+    dir
+}
+
+fn check_version(include_dir: &Path) {
+    let header: PathBuf = include_dir.join("blpapi_versionmacros.h");
+
+    let Ok(content) = fs::read_to_string(&header) else {
+        return;
+    };
+
+    let define = |name: &str| -> Option<u32> {
+        content.lines().find_map(|line| {
+            let mut tokens = line.split_whitespace();
+
+            match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some("#define"), Some(n), Some(value)) if n == name => value.parse().ok(),
+                _ => None,
+            }
+        })
     };
 
-    println!("cargo:rustc-link-search={}", lib_dir);
-    println!("cargo:rustc-link-lib=blpapi3_64");
+    let installed: [u32; 3] = match (
+        define("BLPAPI_VERSION_MAJOR"),
+        define("BLPAPI_VERSION_MINOR"),
+        define("BLPAPI_VERSION_PATCH"),
+    ) {
+        (Some(major), Some(minor), Some(patch)) => [major, minor, patch],
+        _ => return,
+    };
+
+    println!("cargo:rerun-if-changed={}", header.display());
+
+    if installed[..2] != BINDINGS_VERSION[..2] && !cfg!(feature = "bindgen") {
+        println!(
+            "cargo:warning=Installed blpapi {}.{}.{} does not match the checked-in bindings ({}.{}.{}), enable the `bindgen` feature to regenerate them...",
+            installed[0],
+            installed[1],
+            installed[2],
+            BINDINGS_VERSION[0],
+            BINDINGS_VERSION[1],
+            BINDINGS_VERSION[2]
+        );
+    }
+}
+
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include_dir: Option<&Path>) {
+    let mut builder: bindgen::Builder = bindgen::Builder::default()
+        .header("src/wrapper.h")
+        .allowlist_function("blpapi_.*")
+        .allowlist_type("blpapi_.*")
+        .allowlist_var("BLPAPI_.*")
+        .layout_tests(false)
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()));
+
+    if let Some(include_dir) = include_dir {
+        builder = builder.clang_arg(format!("-I{}", include_dir.display()));
+    }
+
+    let out: PathBuf = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("bindings.rs");
+
+    builder
+        .generate()
+        .expect("Failed to generate bindings from `src/wrapper.h`...")
+        .write_to_file(out)
+        .expect("Failed to write generated bindings...");
 }
@@ -0,0 +1,1177 @@
+use crate::{
+    batch::Batch,
+    datetime::{Datetime, HighPrecisionDatetime},
+    element::Element,
+    event::Event,
+    name,
+    overrides::Overrides,
+    ref_data::RefData,
+    request::Request,
+    service::Service,
+    session::{self, Events, HistOptions, SessionSync, TimeSerie},
+    Error,
+};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::path::Path;
+use std::time::Duration;
+
+const VALUES: TableDefinition<&str, &[u8]> = TableDefinition::new("values");
+const RANGES: TableDefinition<&str, &[u8]> = TableDefinition::new("ranges");
+
+const SEPARATOR: &str = "\u{1f}";
+const REF_DATA: &str = "ref";
+const HIST_DATA: &str = "hist";
+
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    stored_at: i64,
+    value: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct Range {
+    start: NaiveDate,
+    end: NaiveDate,
+    stored_at: i64,
+}
+
+struct Key<'a> {
+    kind: &'a str,
+    security: &'a str,
+    field: &'a str,
+    overrides: &'a str,
+}
+
+impl<'a> Key<'a> {
+    fn parse(key: &'a str) -> Option<Self> {
+        let mut parts = key.split(SEPARATOR);
+
+        Some(Key {
+            kind: parts.next()?,
+            security: parts.next()?,
+            field: parts.next()?,
+            overrides: parts.next()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    pub security: String,
+    pub field: String,
+    pub overrides: String,
+    pub dates: Option<(NaiveDate, NaiveDate)>,
+    pub stored_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub struct Cache {
+    db: Database,
+    default_ttl: Duration,
+    ttls: HashMap<String, Duration>,
+}
+
+impl Cache {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let db: Database = Database::create(path).map_err(cache_error)?;
+
+        let txn = db.begin_write().map_err(cache_error)?;
+
+        txn.open_table(VALUES).map_err(cache_error)?;
+        txn.open_table(RANGES).map_err(cache_error)?;
+        txn.commit().map_err(cache_error)?;
+
+        Ok(Cache {
+            db,
+            default_ttl: DEFAULT_TTL,
+            ttls: HashMap::new(),
+        })
+    }
+
+    pub fn with_default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    pub fn with_ttl(mut self, field: &str, ttl: Duration) -> Self {
+        self.ttls.insert(field.to_uppercase(), ttl);
+        self
+    }
+
+    pub fn ttl(&self, field: &str) -> Duration {
+        self.ttls
+            .get(&field.to_uppercase())
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+
+    fn expires_at(&self, field: &str, stored_at: i64) -> i64 {
+        stored_at.saturating_add(self.ttl(field).as_secs() as i64)
+    }
+
+    fn is_fresh(&self, field: &str, stored_at: i64, now: i64) -> bool {
+        now < self.expires_at(field, stored_at)
+    }
+
+    pub fn entries(&self) -> Result<Vec<CacheEntry>, Error> {
+        let txn = self.db.begin_read().map_err(cache_error)?;
+        let values = txn.open_table(VALUES).map_err(cache_error)?;
+        let ranges = txn.open_table(RANGES).map_err(cache_error)?;
+
+        let mut entries: Vec<CacheEntry> = Vec::new();
+
+        for entry in values.iter().map_err(cache_error)? {
+            let (key, value) = entry.map_err(cache_error)?;
+
+            match Key::parse(key.value()) {
+                Some(key) if key.kind == REF_DATA => {
+                    let record: Record = decode(value.value())?;
+
+                    entries.push(self.entry(&key, None, record.stored_at));
+                }
+                _ => (),
+            }
+        }
+
+        for entry in ranges.iter().map_err(cache_error)? {
+            let (key, value) = entry.map_err(cache_error)?;
+
+            if let Some(key) = Key::parse(key.value()) {
+                for range in decode::<Vec<Range>>(value.value())? {
+                    entries.push(self.entry(&key, Some((range.start, range.end)), range.stored_at));
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn entry(
+        &self,
+        key: &Key,
+        dates: Option<(NaiveDate, NaiveDate)>,
+        stored_at: i64,
+    ) -> CacheEntry {
+        CacheEntry {
+            security: key.security.to_owned(),
+            field: key.field.to_owned(),
+            overrides: key.overrides.to_owned(),
+            dates,
+            stored_at: timestamp(stored_at),
+            expires_at: timestamp(self.expires_at(key.field, stored_at)),
+        }
+    }
+
+    pub fn purge(&self) -> Result<usize, Error> {
+        self.purge_where(|_, _| true)
+    }
+
+    pub fn purge_expired(&self) -> Result<usize, Error> {
+        let now: i64 = now();
+
+        self.purge_where(|key, stored_at| !self.is_fresh(key.field, stored_at, now))
+    }
+
+    pub fn purge_security(&self, security: &str) -> Result<usize, Error> {
+        self.purge_where(|key, _| key.security == security)
+    }
+
+    pub fn purge_field(&self, field: &str) -> Result<usize, Error> {
+        let field: String = field.to_uppercase();
+
+        self.purge_where(|key, _| key.field == field)
+    }
+
+    fn purge_where<F: Fn(&Key, i64) -> bool>(&self, purge: F) -> Result<usize, Error> {
+        let txn = self.db.begin_write().map_err(cache_error)?;
+        let mut purged: usize = 0;
+
+        {
+            let mut values = txn.open_table(VALUES).map_err(cache_error)?;
+
+            values
+                .retain(|key, value| {
+                    let stored_at: Option<i64> = decode::<Record>(value).ok().map(|r| r.stored_at);
+
+                    let keep: bool = match (Key::parse(key), stored_at) {
+                        (Some(key), Some(stored_at)) => !purge(&key, stored_at),
+                        _ => false,
+                    };
+
+                    if !keep {
+                        purged += 1;
+                    }
+
+                    keep
+                })
+                .map_err(cache_error)?;
+
+            let mut ranges = txn.open_table(RANGES).map_err(cache_error)?;
+            let mut updates: Vec<(String, Vec<Range>)> = Vec::new();
+
+            for entry in ranges.iter().map_err(cache_error)? {
+                let (key, value) = entry.map_err(cache_error)?;
+                let stored: Vec<Range> = decode(value.value())?;
+
+                let kept: Vec<Range> = match Key::parse(key.value()) {
+                    Some(key) => stored
+                        .iter()
+                        .copied()
+                        .filter(|range| !purge(&key, range.stored_at))
+                        .collect(),
+                    None => Vec::new(),
+                };
+
+                if kept.len() != stored.len() {
+                    purged += stored.len() - kept.len();
+                    updates.push((key.value().to_owned(), kept));
+                }
+            }
+
+            for (key, kept) in updates {
+                if kept.is_empty() {
+                    ranges.remove(&*key).map_err(cache_error)?;
+                } else {
+                    ranges
+                        .insert(&*key, &*encode(&kept)?)
+                        .map_err(cache_error)?;
+                }
+            }
+        }
+
+        txn.commit().map_err(cache_error)?;
+
+        log::debug!("Purged {} cache records...", purged);
+
+        Ok(purged)
+    }
+
+    fn value(&self, key: &str, field: &str) -> Result<Option<Option<Value>>, Error> {
+        let txn = self.db.begin_read().map_err(cache_error)?;
+        let values = txn.open_table(VALUES).map_err(cache_error)?;
+
+        let record: Record = match values.get(key).map_err(cache_error)? {
+            Some(value) => decode(value.value())?,
+            None => return Ok(None),
+        };
+
+        if self.is_fresh(field, record.stored_at, now()) {
+            Ok(Some(record.value))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn ranges(&self, key: &str, field: &str) -> Result<Vec<Range>, Error> {
+        let txn = self.db.begin_read().map_err(cache_error)?;
+        let ranges = txn.open_table(RANGES).map_err(cache_error)?;
+        let now: i64 = now();
+
+        let stored: Vec<Range> = match ranges.get(key).map_err(cache_error)? {
+            Some(value) => decode(value.value())?,
+            None => return Ok(Vec::new()),
+        };
+
+        Ok(stored
+            .into_iter()
+            .filter(|range| self.is_fresh(field, range.stored_at, now))
+            .collect())
+    }
+
+    fn points(
+        &self,
+        key: &str,
+        field: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, Value)>, Error> {
+        let txn = self.db.begin_read().map_err(cache_error)?;
+        let values = txn.open_table(VALUES).map_err(cache_error)?;
+        let now: i64 = now();
+
+        let first: String = point_key(key, start);
+        let last: String = point_key(key, end);
+
+        let mut points: Vec<(NaiveDate, Value)> = Vec::new();
+
+        for entry in values
+            .range::<&str>(first.as_str()..=last.as_str())
+            .map_err(cache_error)?
+        {
+            let (key, value) = entry.map_err(cache_error)?;
+            let record: Record = decode(value.value())?;
+
+            let date: Option<NaiveDate> = key
+                .value()
+                .rsplit(SEPARATOR)
+                .next()
+                .and_then(|date| date.parse().ok());
+
+            match (date, record.value) {
+                (Some(date), Some(value)) if self.is_fresh(field, record.stored_at, now) => {
+                    points.push((date, value))
+                }
+                _ => (),
+            }
+        }
+
+        Ok(points)
+    }
+
+    fn store(
+        &self,
+        values: Vec<(String, Option<Value>)>,
+        ranges: Vec<(String, String, Range)>,
+    ) -> Result<(), Error> {
+        let txn = self.db.begin_write().map_err(cache_error)?;
+        let now: i64 = now();
+
+        {
+            let mut table = txn.open_table(VALUES).map_err(cache_error)?;
+
+            for (key, value) in values {
+                let record: Record = Record {
+                    stored_at: now,
+                    value,
+                };
+
+                table
+                    .insert(&*key, &*encode(&record)?)
+                    .map_err(cache_error)?;
+            }
+
+            let mut table = txn.open_table(RANGES).map_err(cache_error)?;
+
+            for (key, field, range) in ranges {
+                let mut stored: Vec<Range> = match table.get(&*key).map_err(cache_error)? {
+                    Some(value) => decode(value.value())?,
+                    None => Vec::new(),
+                };
+
+                stored.retain(|r| self.is_fresh(&field, r.stored_at, now));
+                stored.push(range);
+
+                table
+                    .insert(&*key, &*encode(&stored)?)
+                    .map_err(cache_error)?;
+            }
+        }
+
+        txn.commit().map_err(cache_error)
+    }
+}
+
+impl std::fmt::Debug for Cache {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Cache: '{:?}'...", self.default_ttl)
+    }
+}
+
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl FromValue for i8 {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) if s.len() == 1 => Some(s.as_bytes()[0] as i8),
+            _ => value.as_i64()?.try_into().ok(),
+        }
+    }
+}
+
+impl FromValue for i32 {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_i64()?.try_into().ok()
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_i64()
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_f64().map(|v| v as f32)
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for Datetime {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_str()?.parse().ok()
+    }
+}
+
+impl FromValue for HighPrecisionDatetime {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_str()?.parse().ok()
+    }
+}
+
+impl FromValue for NaiveDate {
+    fn from_value(value: &Value) -> Option<Self> {
+        Datetime::from_value(value)?.try_into().ok()
+    }
+}
+
+impl FromValue for NaiveTime {
+    fn from_value(value: &Value) -> Option<Self> {
+        HighPrecisionDatetime::from_value(value)?.try_into().ok()
+    }
+}
+
+impl FromValue for NaiveDateTime {
+    fn from_value(value: &Value) -> Option<Self> {
+        HighPrecisionDatetime::from_value(value)?.try_into().ok()
+    }
+}
+
+impl FromValue for DateTime<FixedOffset> {
+    fn from_value(value: &Value) -> Option<Self> {
+        HighPrecisionDatetime::from_value(value)?.try_into().ok()
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: &Value) -> Option<Self> {
+        T::from_value(value).map(Some)
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: &Value) -> Option<Self> {
+        Some(values(value).iter().map_while(T::from_value).collect())
+    }
+}
+
+impl<T: FromValue + Hash + Eq> FromValue for HashSet<T> {
+    fn from_value(value: &Value) -> Option<Self> {
+        Some(values(value).iter().map_while(T::from_value).collect())
+    }
+}
+
+fn values(value: &Value) -> &[Value] {
+    match value {
+        Value::Array(values) => values,
+        Value::Null => &[],
+        value => std::slice::from_ref(value),
+    }
+}
+
+/// Applies cached values the way [`RefData::on_field`] applies elements. Derived with `RefData`.
+pub trait CacheData: RefData {
+    fn on_value(&mut self, field: &str, value: &Value);
+}
+
+trait Fetch {
+    fn service(&mut self) -> Result<Service, Error>;
+
+    fn fetch(&mut self, request: Request) -> Result<Events<'_>, Error>;
+}
+
+impl Fetch for SessionSync {
+    fn service(&mut self) -> Result<Service, Error> {
+        self.get_service("//blp/refdata")
+    }
+
+    fn fetch(&mut self, request: Request) -> Result<Events<'_>, Error> {
+        self.send(request, None)
+    }
+}
+
+pub struct CachedSession {
+    session: SessionSync,
+    cache: Cache,
+}
+
+impl CachedSession {
+    pub fn new(session: SessionSync, cache: Cache) -> Self {
+        CachedSession { session, cache }
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    pub fn into_inner(self) -> (SessionSync, Cache) {
+        (self.session, self.cache)
+    }
+
+    pub fn ref_data<I, R>(&mut self, securities: I) -> Result<Batch<HashMap<String, R>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: CacheData,
+    {
+        self.ref_data_with_overrides(securities, &Overrides::default())
+    }
+
+    pub fn ref_data_with_overrides<I, R>(
+        &mut self,
+        securities: I,
+        overrides: &Overrides,
+    ) -> Result<Batch<HashMap<String, R>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: CacheData,
+    {
+        let securities: Vec<String> = session::collect_securities(securities);
+
+        ref_data(&mut self.session, &self.cache, &securities, overrides)
+    }
+
+    pub fn hist_data<I, R>(
+        &mut self,
+        securities: I,
+        options: HistOptions,
+    ) -> Result<Batch<HashMap<String, TimeSerie<R>>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: CacheData,
+    {
+        let dates: (NaiveDate, NaiveDate) = match options.dates() {
+            Some(dates) if options.is_cacheable() => dates,
+            _ => {
+                log::debug!("Historical request cannot be cached...");
+
+                return self.session.hist_data(securities, options);
+            }
+        };
+
+        let securities: Vec<String> = session::collect_securities(securities);
+
+        hist_data(&mut self.session, &self.cache, &securities, &options, dates)
+    }
+}
+
+impl std::ops::Deref for CachedSession {
+    type Target = SessionSync;
+
+    fn deref(&self) -> &SessionSync {
+        &self.session
+    }
+}
+
+impl std::ops::DerefMut for CachedSession {
+    fn deref_mut(&mut self) -> &mut SessionSync {
+        &mut self.session
+    }
+}
+
+fn ref_data<S: Fetch, R: CacheData>(
+    source: &mut S,
+    cache: &Cache,
+    securities: &[String],
+    overrides: &Overrides,
+) -> Result<Batch<HashMap<String, R>>, Error> {
+    let overrides: Overrides = Overrides::from(R::OVERRIDES).merge(overrides);
+    let overrides_key: String = overrides_key(&overrides);
+
+    let ref_key = |security: &str, field: &str| {
+        key(&[REF_DATA, security, &field.to_uppercase(), &overrides_key])
+    };
+
+    let mut ref_data: Batch<HashMap<String, R>> = Batch::default();
+    let mut missing_securities: Vec<String> = Vec::new();
+    let mut missing_fields: Vec<&str> = Vec::new();
+
+    for security in securities.iter() {
+        let mut cached: Vec<(&str, Value)> = Vec::new();
+        let mut complete: bool = true;
+
+        for field in R::FIELDS {
+            match cache.value(&ref_key(security, field), field)? {
+                Some(Some(value)) => cached.push((field, value)),
+                Some(None) => (),
+                None => {
+                    complete = false;
+
+                    if !missing_fields.contains(field) {
+                        missing_fields.push(field);
+                    }
+                }
+            }
+        }
+
+        if !complete {
+            missing_securities.push(security.clone());
+        }
+
+        if complete || !cached.is_empty() {
+            let entry: &mut R = ref_data.data.entry(security.clone()).or_default();
+
+            for (field, value) in cached {
+                entry.on_value(field, &value);
+            }
+        }
+    }
+
+    if missing_securities.is_empty() {
+        return Ok(ref_data);
+    }
+
+    log::debug!(
+        "{} securities cached, fetching {} fields for {} securities...",
+        securities.len() - missing_securities.len(),
+        missing_fields.len(),
+        missing_securities.len()
+    );
+
+    let service: Service = source.service()?;
+    let mut capture: Capture = Capture::new(&missing_fields);
+
+    for request in
+        session::ref_data_requests(&service, &missing_securities, &missing_fields, &overrides)
+    {
+        for event in source.fetch(request?)? {
+            let event: Event = event?;
+
+            session::on_ref_data(&event, &mut ref_data)?;
+            capture.on_ref_data(&event);
+        }
+    }
+
+    let mut values: Vec<(String, Option<Value>)> = Vec::new();
+
+    for (security, field) in capture.received(&ref_data.errors) {
+        let value: Option<Value> = capture.values.remove(&(security.clone(), field, None));
+
+        values.push((ref_key(&security, field), value));
+    }
+
+    cache.store(values, Vec::new())?;
+
+    Ok(ref_data)
+}
+
+fn hist_data<S: Fetch, R: CacheData>(
+    source: &mut S,
+    cache: &Cache,
+    securities: &[String],
+    options: &HistOptions,
+    (start, end): (NaiveDate, NaiveDate),
+) -> Result<Batch<HashMap<String, TimeSerie<R>>>, Error> {
+    let overrides: Overrides = Overrides::from(R::OVERRIDES).merge(options.overrides());
+    let overrides_key: String = overrides_key(&overrides);
+    let options_key: String = options.key();
+
+    let hist_key = |security: &str, field: &str| -> String {
+        key(&[
+            HIST_DATA,
+            security,
+            &field.to_uppercase(),
+            &overrides_key,
+            &options_key,
+        ])
+    };
+
+    let mut hist_data: Batch<HashMap<String, TimeSerie<R>>> = Batch::default();
+    let mut missing: Option<(NaiveDate, NaiveDate)> = None;
+    let mut missing_securities: Vec<String> = Vec::new();
+    let mut missing_fields: Vec<&str> = Vec::new();
+
+    for security in securities.iter() {
+        for field in R::FIELDS {
+            let key: String = hist_key(security, field);
+
+            if let Some((first, last)) = uncovered(start, end, &cache.ranges(&key, field)?) {
+                missing = Some(match missing {
+                    Some((s, e)) => (s.min(first), e.max(last)),
+                    None => (first, last),
+                });
+
+                if !missing_securities.contains(security) {
+                    missing_securities.push(security.clone());
+                }
+
+                if !missing_fields.contains(field) {
+                    missing_fields.push(field);
+                }
+            }
+
+            for (date, value) in cache.points(&key, field, start, end)? {
+                hist_data
+                    .data
+                    .entry(security.clone())
+                    .or_default()
+                    .entry(date)
+                    .on_value(field, &value);
+            }
+        }
+    }
+
+    let (first, last): (NaiveDate, NaiveDate) = match missing {
+        Some(missing) => missing,
+        None => return Ok(hist_data),
+    };
+
+    log::debug!(
+        "Fetching {} fields for {} securities from {} to {}...",
+        missing_fields.len(),
+        missing_securities.len(),
+        first,
+        last
+    );
+
+    let service: Service = source.service()?;
+    let fetch: HistOptions = options.with_dates(first, last);
+    let mut capture: Capture = Capture::new(&missing_fields);
+
+    for request in session::hist_data_requests(
+        &service,
+        &missing_securities,
+        &missing_fields,
+        &fetch,
+        &overrides,
+    ) {
+        for event in source.fetch(request?)? {
+            let event: Event = event?;
+
+            session::on_hist_data(&event, &mut hist_data)?;
+            capture.on_hist_data(&event);
+        }
+    }
+
+    let covered: NaiveDate = last.min(yesterday());
+    let mut ranges: Vec<(String, String, Range)> = Vec::new();
+
+    for (security, field) in capture.received(&hist_data.errors) {
+        hist_data.data.entry(security.clone()).or_default();
+
+        if first <= covered {
+            let range: Range = Range {
+                start: first,
+                end: covered,
+                stored_at: now(),
+            };
+
+            ranges.push((hist_key(&security, field), field.to_owned(), range));
+        }
+    }
+
+    let mut values: Vec<(String, Option<Value>)> = Vec::new();
+
+    for ((security, field, date), value) in capture.values {
+        match date {
+            Some(date) if date <= covered => {
+                values.push((point_key(&hist_key(&security, field), date), Some(value)))
+            }
+            _ => (),
+        }
+    }
+
+    cache.store(values, ranges)?;
+
+    Ok(hist_data)
+}
+
+struct Capture<'a> {
+    fields: &'a [&'a str],
+    securities: Vec<String>,
+    values: HashMap<(String, &'a str, Option<NaiveDate>), Value>,
+}
+
+impl<'a> Capture<'a> {
+    fn new(fields: &'a [&'a str]) -> Self {
+        Capture {
+            fields,
+            securities: Vec::new(),
+            values: HashMap::new(),
+        }
+    }
+
+    fn on_security(&mut self, security: &Element) -> Option<String> {
+        let ticker: String = session::on_security(security, &mut Vec::new())?;
+
+        if !self.securities.contains(&ticker) {
+            self.securities.push(ticker.clone());
+        }
+
+        Some(ticker)
+    }
+
+    fn on_fields(&mut self, ticker: &str, fields: &Element, date: Option<NaiveDate>) {
+        for field in fields.elements() {
+            let name: String = field.string_name();
+
+            let requested: &'a str =
+                match self.fields.iter().find(|f| f.eq_ignore_ascii_case(&name)) {
+                    Some(requested) => requested,
+                    None => continue,
+                };
+
+            match serde_json::to_value(&field) {
+                Ok(value) => {
+                    self.values
+                        .insert((ticker.to_owned(), requested, date), value);
+                }
+                Err(e) => log::warn!("Cannot cache {} {}: {}...", ticker, name, e),
+            }
+        }
+    }
+
+    fn on_ref_data(&mut self, event: &Event) {
+        for message in event.messages().map(|m| m.element()) {
+            if let Some(securities) = message.get_named_element(&name::SECURITY_DATA) {
+                for security in securities.values::<Element>() {
+                    let ticker: String = match self.on_security(&security) {
+                        Some(ticker) => ticker,
+                        None => continue,
+                    };
+
+                    if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+                        self.on_fields(&ticker, &fields, None);
+                    }
+                }
+            }
+        }
+    }
+
+    fn on_hist_data(&mut self, event: &Event) {
+        for message in event.messages().map(|m| m.element()) {
+            let security: Element = match message.get_named_element(&name::SECURITY_DATA) {
+                Some(security) => security,
+                None => continue,
+            };
+
+            let ticker: String = match self.on_security(&security) {
+                Some(ticker) => ticker,
+                None => continue,
+            };
+
+            if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+                for point in fields.values::<Element>() {
+                    match point.element_value::<NaiveDate>("date") {
+                        Some(date) => self.on_fields(&ticker, &point, Some(date)),
+                        None => log::warn!("Missing date in historical data..."),
+                    }
+                }
+            }
+        }
+    }
+
+    fn received(&self, errors: &[Error]) -> Vec<(String, &'a str)> {
+        let mut received: Vec<(String, &'a str)> = Vec::new();
+
+        for security in self.securities.iter() {
+            for field in self.fields.iter() {
+                let failed: bool = errors.iter().any(|e| match e {
+                    Error::Field {
+                        security: s,
+                        field: f,
+                        ..
+                    } => s == security && f.eq_ignore_ascii_case(field),
+                    _ => false,
+                });
+
+                if !failed {
+                    received.push((security.clone(), *field));
+                }
+            }
+        }
+
+        received
+    }
+}
+
+fn uncovered(start: NaiveDate, end: NaiveDate, ranges: &[Range]) -> Option<(NaiveDate, NaiveDate)> {
+    let covered = |date: &NaiveDate| ranges.iter().any(|r| r.start <= *date && *date <= r.end);
+
+    let first: NaiveDate = start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .find(|date| !covered(date))?;
+
+    let last: NaiveDate = first
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .filter(|date| !covered(date))
+        .last()?;
+
+    Some((first, last))
+}
+
+fn key(parts: &[&str]) -> String {
+    parts.join(SEPARATOR)
+}
+
+fn point_key(key: &str, date: NaiveDate) -> String {
+    format!("{}{}{}", key, SEPARATOR, date)
+}
+
+fn overrides_key(overrides: &Overrides) -> String {
+    let mut overrides: Vec<String> = overrides
+        .iter()
+        .map(|(field, value)| format!("{}={}", field.to_uppercase(), value))
+        .collect();
+
+    overrides.sort();
+
+    overrides.join(",")
+}
+
+fn yesterday() -> NaiveDate {
+    let today: NaiveDate = Utc::now().date_naive();
+
+    today.pred_opt().unwrap_or(today)
+}
+
+fn now() -> i64 {
+    Utc::now().timestamp()
+}
+
+fn timestamp(seconds: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(seconds, 0).unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+    serde_json::to_vec(value).map_err(cache_error)
+}
+
+fn decode<'a, T: Deserialize<'a>>(bytes: &'a [u8]) -> Result<T, Error> {
+    serde_json::from_slice(bytes).map_err(cache_error)
+}
+
+fn cache_error<E: std::error::Error + Send + Sync + 'static>(e: E) -> Error {
+    Error::Cache(Box::new(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(name: &str) -> Cache {
+        let path =
+            std::env::temp_dir().join(format!("bloomberg-{}-{}.redb", name, std::process::id()));
+
+        let _ = std::fs::remove_file(&path);
+
+        Cache::open(path).unwrap()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    #[test]
+    fn uncovered_dates() {
+        let range = |start: u32, end: u32| Range {
+            start: date(start),
+            end: date(end),
+            stored_at: 0,
+        };
+
+        assert_eq!(uncovered(date(1), date(10), &[]), Some((date(1), date(10))));
+        assert_eq!(uncovered(date(1), date(10), &[range(1, 10)]), None);
+        assert_eq!(
+            uncovered(date(1), date(10), &[range(1, 3), range(8, 12)]),
+            Some((date(4), date(7)))
+        );
+    }
+
+    #[test]
+    fn ref_values() -> Result<(), Error> {
+        let cache: Cache = cache("ref").with_ttl("px_last", Duration::ZERO);
+
+        let name: String = key(&[REF_DATA, "IBM US Equity", "NAME", ""]);
+        let price: String = key(&[REF_DATA, "IBM US Equity", "PX_LAST", ""]);
+
+        cache.store(
+            vec![
+                (name.clone(), Some(Value::from("IBM"))),
+                (price.clone(), Some(Value::from(140.5))),
+            ],
+            Vec::new(),
+        )?;
+
+        assert!(matches!(
+            cache.value(&name, "NAME")?,
+            Some(Some(Value::String(v))) if v == "IBM"
+        ));
+        assert!(cache.value(&price, "PX_LAST")?.is_none());
+        assert_eq!(cache.entries()?.len(), 2);
+
+        assert_eq!(cache.purge_expired()?, 1);
+        assert_eq!(cache.purge_security("IBM US Equity")?, 1);
+        assert!(cache.entries()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn hist_points() -> Result<(), Error> {
+        let cache: Cache = cache("hist");
+        let key: String = key(&[HIST_DATA, "IBM US Equity", "PX_LAST", "", ""]);
+
+        let range: Range = Range {
+            start: date(1),
+            end: date(5),
+            stored_at: now(),
+        };
+
+        cache.store(
+            vec![
+                (point_key(&key, date(2)), Some(Value::from(1.))),
+                (point_key(&key, date(3)), Some(Value::from(2.))),
+            ],
+            vec![(key.clone(), "PX_LAST".to_owned(), range)],
+        )?;
+
+        assert_eq!(cache.ranges(&key, "PX_LAST")?, vec![range]);
+        assert_eq!(cache.points(&key, "PX_LAST", date(3), date(10))?.len(), 1);
+        assert_eq!(
+            uncovered(date(1), date(10), &cache.ranges(&key, "PX_LAST")?),
+            Some((date(6), date(10)))
+        );
+
+        let entries: Vec<CacheEntry> = cache.entries()?;
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].dates, Some((date(1), date(5))));
+
+        assert_eq!(cache.purge_field("px_last")?, 3);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, Default, PartialEq, crate::FromElement)]
+    struct DividendRow {
+        #[bbg(field = "Ex-Date")]
+        ex_date: Option<NaiveDate>,
+        dividend_amount: f64,
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, Default, crate::RefData)]
+    struct Security {
+        px_last: f64,
+        #[bbg(field = "NAME")]
+        security_name: String,
+        #[bbg(default)]
+        volume: Option<i64>,
+        dvd_hist_all: Vec<DividendRow>,
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn cached_values() {
+        let mut security: Security = Security::default();
+
+        security.on_value("PX_LAST", &Value::from(140));
+        security.on_value("NAME", &Value::from("IBM"));
+        security.on_value("VOLUME", &Value::from("n/a"));
+        security.on_value(
+            "DVD_HIST_ALL",
+            &serde_json::json!([
+                { "Ex-Date": "2024-01-02", "Dividend Amount": 1.5 },
+                { "Ex-Date": null, "Dividend Amount": 1.25 },
+            ]),
+        );
+
+        assert_eq!(security.px_last, 140.);
+        assert_eq!(security.security_name, "IBM");
+        assert_eq!(security.volume, None);
+        assert_eq!(
+            security.dvd_hist_all,
+            [
+                DividendRow {
+                    ex_date: Some(date(2)),
+                    dividend_amount: 1.5
+                },
+                DividendRow {
+                    ex_date: None,
+                    dividend_amount: 1.25
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    impl Fetch for crate::mock::MockSession {
+        fn service(&mut self) -> Result<Service, Error> {
+            crate::mock::deserialize_service(crate::mock::REFDATA_SCHEMA)
+        }
+
+        fn fetch(&mut self, _request: Request) -> Result<Events<'_>, Error> {
+            Ok(self.events())
+        }
+    }
+
+    #[cfg(all(feature = "derive", feature = "mock"))]
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn merge_fields() -> Result<(), Error> {
+        use crate::mock::{self, MockElement, MockEvent, MockMessage, MockSession};
+
+        let cache: Cache = cache("merge");
+        let securities: Vec<String> = vec!["IBM US Equity".to_owned()];
+
+        cache.store(
+            vec![(
+                key(&[REF_DATA, "IBM US Equity", "NAME", ""]),
+                Some(Value::from("IBM")),
+            )],
+            Vec::new(),
+        )?;
+
+        let mut session: MockSession = MockSession::new()
+            .with_service(mock::deserialize_service(mock::REFDATA_SCHEMA)?)
+            .with(MockEvent::response().with_message(
+                MockMessage::new("ReferenceDataResponse").with(
+                    "securityData",
+                    vec![MockElement::new()
+                        .with("security", "IBM US Equity")
+                        .with("fieldData", MockElement::new().with("PX_LAST", 140.5))],
+                ),
+            ))?;
+
+        let merged: Batch<HashMap<String, Security>> =
+            ref_data(&mut session, &cache, &securities, &Overrides::default())?;
+
+        assert!(session.is_empty());
+        assert_eq!(merged["IBM US Equity"].security_name, "IBM");
+        assert_eq!(merged["IBM US Equity"].px_last, 140.5);
+
+        let cached: Batch<HashMap<String, Security>> =
+            ref_data(&mut session, &cache, &securities, &Overrides::default())?;
+
+        assert_eq!(cached["IBM US Equity"].security_name, "IBM");
+        assert_eq!(cached["IBM US Equity"].px_last, 140.5);
+
+        Ok(())
+    }
+}
@@ -3,7 +3,9 @@ use std::os::raw::c_uint;
 
 const DEFAULT_CLASS_ID: c_uint = 0;
 
+#[derive(Clone, Copy)]
 pub struct CorrelationId(pub(crate) blpapi_CorrelationId_t);
+
 impl CorrelationId {
     pub fn new_u64(value: u64) -> Self {
         let size: u32 = std::mem::size_of::<blpapi_CorrelationId_t>() as c_uint;
@@ -21,6 +23,23 @@ impl CorrelationId {
 
         CorrelationId(inner)
     }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        if self.0.valueType() == BLPAPI_CORRELATION_TYPE_INT {
+            Some(unsafe { self.0.value.intValue })
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Debug for CorrelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.as_u64() {
+            Some(id) => write!(f, "CorrelationId({})", id),
+            None => write!(f, "CorrelationId(..)"),
+        }
+    }
 }
 
 #[test]
@@ -28,4 +47,5 @@ fn correlation_u64() {
     let id: CorrelationId = CorrelationId::new_u64(1);
 
     assert_eq!(unsafe { id.0.value.intValue }, 1);
+    assert_eq!(id.as_u64(), Some(1));
 }
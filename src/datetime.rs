@@ -1,6 +1,13 @@
 use crate::bindings::*;
+use crate::Error;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::convert::TryFrom;
 use std::os::raw::c_int;
+use std::str::FromStr;
 
+const PICOS_PER_MILLI: u64 = 1_000_000_000;
+
+#[derive(Clone, Copy)]
 pub struct Datetime(pub(crate) blpapi_Datetime_t);
 
 impl Default for Datetime {
@@ -19,6 +26,317 @@ impl Default for Datetime {
     }
 }
 
+impl Datetime {
+    pub fn has_date(&self) -> bool {
+        self.0.parts as u32 & BLPAPI_DATETIME_DATE_PART == BLPAPI_DATETIME_DATE_PART
+    }
+
+    pub fn has_time(&self) -> bool {
+        self.0.parts as u32 & BLPAPI_DATETIME_HOURS_PART != 0
+    }
+
+    pub fn has_milliseconds(&self) -> bool {
+        self.0.parts as u32 & BLPAPI_DATETIME_MILLISECONDS_PART != 0
+    }
+
+    pub fn offset(&self) -> Option<FixedOffset> {
+        if self.0.parts as u32 & BLPAPI_DATETIME_OFFSET_PART == 0 {
+            return None;
+        }
+
+        FixedOffset::east_opt(self.0.offset as i32 * 60)
+    }
+
+    pub fn with_offset(mut self, offset: FixedOffset) -> Self {
+        self.0.parts |= BLPAPI_DATETIME_OFFSET_PART as u8;
+        self.0.offset = (offset.local_minus_utc() / 60) as i16;
+
+        self
+    }
+
+    fn date(&self) -> Result<NaiveDate, Error> {
+        if !self.has_date() {
+            return Err(Error::Conversion(format!("{} has no date", self)));
+        }
+
+        NaiveDate::from_ymd_opt(self.0.year as i32, self.0.month as u32, self.0.day as u32)
+            .ok_or_else(|| Error::Conversion(format!("{} is not a valid date", self)))
+    }
+
+    fn time(&self, nanoseconds: u32) -> Result<NaiveTime, Error> {
+        if !self.has_time() {
+            return Ok(NaiveTime::MIN);
+        }
+
+        NaiveTime::from_hms_nano_opt(
+            self.0.hours as u32,
+            self.0.minutes as u32,
+            self.0.seconds as u32,
+            nanoseconds,
+        )
+        .ok_or_else(|| Error::Conversion(format!("{} is not a valid time", self)))
+    }
+}
+
+impl From<NaiveDate> for Datetime {
+    fn from(date: NaiveDate) -> Self {
+        Datetime(blpapi_Datetime_t {
+            parts: BLPAPI_DATETIME_DATE_PART as u8,
+            year: date.year() as u16,
+            month: date.month() as u8,
+            day: date.day() as u8,
+            ..Datetime::default().0
+        })
+    }
+}
+
+impl From<NaiveTime> for Datetime {
+    fn from(time: NaiveTime) -> Self {
+        Datetime(blpapi_Datetime_t {
+            parts: BLPAPI_DATETIME_TIMEMILLI_PART as u8,
+            hours: time.hour() as u8,
+            minutes: time.minute() as u8,
+            seconds: time.second() as u8,
+            milliSeconds: (time.nanosecond() / 1_000_000).min(999) as u16,
+            ..Datetime::default().0
+        })
+    }
+}
+
+impl From<NaiveDateTime> for Datetime {
+    fn from(datetime: NaiveDateTime) -> Self {
+        let mut inner: blpapi_Datetime_t = Datetime::from(datetime.time()).0;
+        let date: blpapi_Datetime_t = Datetime::from(datetime.date()).0;
+
+        inner.parts |= date.parts;
+        inner.year = date.year;
+        inner.month = date.month;
+        inner.day = date.day;
+
+        Datetime(inner)
+    }
+}
+
+impl From<DateTime<FixedOffset>> for Datetime {
+    fn from(datetime: DateTime<FixedOffset>) -> Self {
+        Datetime::from(datetime.naive_local()).with_offset(*datetime.offset())
+    }
+}
+
+impl TryFrom<Datetime> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(datetime: Datetime) -> Result<Self, Error> {
+        datetime.date()
+    }
+}
+
+impl TryFrom<Datetime> for NaiveTime {
+    type Error = Error;
+
+    fn try_from(datetime: Datetime) -> Result<Self, Error> {
+        if !datetime.has_time() {
+            return Err(Error::Conversion(format!("{} has no time", datetime)));
+        }
+
+        datetime.time(datetime.0.milliSeconds as u32 * 1_000_000)
+    }
+}
+
+impl TryFrom<Datetime> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: Datetime) -> Result<Self, Error> {
+        let time: NaiveTime = datetime.time(datetime.0.milliSeconds as u32 * 1_000_000)?;
+
+        Ok(datetime.date()?.and_time(time))
+    }
+}
+
+impl TryFrom<Datetime> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(datetime: Datetime) -> Result<Self, Error> {
+        let offset: FixedOffset = datetime
+            .offset()
+            .ok_or_else(|| Error::Conversion(format!("{} has no offset", datetime)))?;
+
+        with_offset(NaiveDateTime::try_from(datetime)?, offset)
+    }
+}
+
+impl std::fmt::Display for Datetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let fraction: Option<String> = if self.has_milliseconds() {
+            Some(format!("{:03}", self.0.milliSeconds))
+        } else {
+            None
+        };
+
+        write_iso(f, &self.0, fraction)
+    }
+}
+
+impl std::fmt::Debug for Datetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Datetime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (datetime, picoseconds): (Datetime, u64) = parse(s)?;
+
+        if picoseconds % PICOS_PER_MILLI != 0 {
+            return Err(Error::Conversion(format!(
+                "'{}' is more precise than milliseconds",
+                s
+            )));
+        }
+
+        Ok(datetime)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct HighPrecisionDatetime(pub(crate) blpapi_HighPrecisionDatetime_t);
+
+impl HighPrecisionDatetime {
+    pub fn new(datetime: Datetime, picoseconds: u32) -> Self {
+        HighPrecisionDatetime(blpapi_HighPrecisionDatetime_t {
+            datetime: datetime.0,
+            picoseconds,
+        })
+    }
+
+    pub fn datetime(&self) -> Datetime {
+        Datetime(self.0.datetime)
+    }
+
+    pub fn picoseconds(&self) -> u64 {
+        self.0.datetime.milliSeconds as u64 * PICOS_PER_MILLI + self.0.picoseconds as u64
+    }
+
+    pub fn offset(&self) -> Option<FixedOffset> {
+        self.datetime().offset()
+    }
+
+    fn nanoseconds(&self) -> u32 {
+        (self.picoseconds() / 1_000) as u32
+    }
+}
+
+impl Default for HighPrecisionDatetime {
+    fn default() -> Self {
+        HighPrecisionDatetime::new(Datetime::default(), 0)
+    }
+}
+
+impl From<Datetime> for HighPrecisionDatetime {
+    fn from(datetime: Datetime) -> Self {
+        HighPrecisionDatetime::new(datetime, 0)
+    }
+}
+
+impl From<NaiveTime> for HighPrecisionDatetime {
+    fn from(time: NaiveTime) -> Self {
+        let picoseconds: u32 = (time.nanosecond() % 1_000_000) * 1_000;
+
+        HighPrecisionDatetime::new(time.into(), picoseconds)
+    }
+}
+
+impl From<NaiveDateTime> for HighPrecisionDatetime {
+    fn from(datetime: NaiveDateTime) -> Self {
+        let picoseconds: u32 = (datetime.nanosecond() % 1_000_000) * 1_000;
+
+        HighPrecisionDatetime::new(datetime.into(), picoseconds)
+    }
+}
+
+impl From<DateTime<FixedOffset>> for HighPrecisionDatetime {
+    fn from(datetime: DateTime<FixedOffset>) -> Self {
+        let picoseconds: u32 = (datetime.nanosecond() % 1_000_000) * 1_000;
+
+        HighPrecisionDatetime::new(datetime.into(), picoseconds)
+    }
+}
+
+impl TryFrom<HighPrecisionDatetime> for NaiveTime {
+    type Error = Error;
+
+    fn try_from(datetime: HighPrecisionDatetime) -> Result<Self, Error> {
+        if !datetime.datetime().has_time() {
+            return Err(Error::Conversion(format!("{} has no time", datetime)));
+        }
+
+        datetime.datetime().time(datetime.nanoseconds())
+    }
+}
+
+impl TryFrom<HighPrecisionDatetime> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: HighPrecisionDatetime) -> Result<Self, Error> {
+        let inner: Datetime = datetime.datetime();
+        let time: NaiveTime = inner.time(datetime.nanoseconds())?;
+
+        Ok(inner.date()?.and_time(time))
+    }
+}
+
+impl TryFrom<HighPrecisionDatetime> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(datetime: HighPrecisionDatetime) -> Result<Self, Error> {
+        let offset: FixedOffset = datetime
+            .offset()
+            .ok_or_else(|| Error::Conversion(format!("{} has no offset", datetime)))?;
+
+        with_offset(NaiveDateTime::try_from(datetime)?, offset)
+    }
+}
+
+impl std::fmt::Display for HighPrecisionDatetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let fraction: Option<String> = if self.datetime().has_milliseconds() {
+            let digits: String = format!("{:012}", self.picoseconds());
+            let mut len: usize = 12;
+
+            while len > 3 && digits[len - 3..len] == *"000" {
+                len -= 3;
+            }
+
+            Some(digits[..len].to_owned())
+        } else {
+            None
+        };
+
+        write_iso(f, &self.0.datetime, fraction)
+    }
+}
+
+impl std::fmt::Debug for HighPrecisionDatetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for HighPrecisionDatetime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (datetime, picoseconds): (Datetime, u64) = parse(s)?;
+
+        Ok(HighPrecisionDatetime::new(
+            datetime,
+            (picoseconds % PICOS_PER_MILLI) as u32,
+        ))
+    }
+}
+
 pub enum DatetimeParts {
     Year,
     Month,
@@ -58,26 +376,201 @@ pub fn is_leap_year(y: c_int) -> bool {
     y % 4 == 0 && (y <= 1752 || y % 100 != 0 || y % 400 == 0)
 }
 
-impl std::fmt::Debug for Datetime {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let d: blpapi_Datetime_tag = self.0;
-
-        match (
-            d.year,
-            d.month,
-            d.day,
-            d.hours,
-            d.minutes,
-            d.seconds,
-            d.milliSeconds,
-        ) {
-            (y, m, d, 0, 0, 0, 0) => write!(f, "{:04}-{:02}-{:02}", y, m, d),
-            (0, 0, 0, h, mm, s, ms) => write!(f, "{:02}:{:02}:{:02}.{:03}", h, mm, s, ms),
-            (y, m, d, h, mm, s, ms) => write!(
-                f,
-                "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}",
-                y, m, d, h, mm, s, ms
-            ),
+fn with_offset(
+    datetime: NaiveDateTime,
+    offset: FixedOffset,
+) -> Result<DateTime<FixedOffset>, Error> {
+    datetime
+        .and_local_timezone(offset)
+        .single()
+        .ok_or_else(|| Error::Conversion(format!("{} is ambiguous in {}", datetime, offset)))
+}
+
+fn write_iso(
+    f: &mut std::fmt::Formatter,
+    d: &blpapi_Datetime_t,
+    fraction: Option<String>,
+) -> std::fmt::Result {
+    let parts: u32 = d.parts as u32;
+
+    let has_date: bool = parts & BLPAPI_DATETIME_DATE_PART == BLPAPI_DATETIME_DATE_PART;
+    let has_time: bool = parts & BLPAPI_DATETIME_HOURS_PART != 0;
+
+    if has_date {
+        write!(f, "{:04}-{:02}-{:02}", d.year, d.month, d.day)?;
+    }
+
+    if has_time {
+        if has_date {
+            write!(f, "T")?;
+        }
+
+        write!(f, "{:02}:{:02}:{:02}", d.hours, d.minutes, d.seconds)?;
+
+        if let Some(fraction) = fraction {
+            write!(f, ".{}", fraction)?;
         }
     }
+
+    if parts & BLPAPI_DATETIME_OFFSET_PART != 0 {
+        let sign: char = if d.offset < 0 { '-' } else { '+' };
+
+        write!(
+            f,
+            "{}{:02}:{:02}",
+            sign,
+            d.offset.abs() / 60,
+            d.offset.abs() % 60
+        )?;
+    }
+
+    Ok(())
+}
+
+fn parse(s: &str) -> Result<(Datetime, u64), Error> {
+    let invalid = || Error::Conversion(format!("'{}' is not an ISO 8601 datetime", s));
+
+    let trimmed: &str = s.trim();
+
+    let (date, rest): (Option<&str>, &str) = if trimmed.contains(':') {
+        match trimmed.find(['T', ' ']) {
+            Some(i) => (Some(&trimmed[..i]), &trimmed[i + 1..]),
+            None => (None, trimmed),
+        }
+    } else {
+        (Some(trimmed), "")
+    };
+
+    let mut datetime: Datetime = match date {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| invalid())?
+            .into(),
+        None => Datetime::default(),
+    };
+
+    if rest.is_empty() {
+        return Ok((datetime, 0));
+    }
+
+    let (time, offset): (&str, Option<&str>) = if let Some(time) = rest.strip_suffix('Z') {
+        (time, Some("+00:00"))
+    } else {
+        match rest.find(['+', '-']) {
+            Some(i) => (&rest[..i], Some(&rest[i..])),
+            None => (rest, None),
+        }
+    };
+
+    let (time, fraction): (&str, Option<&str>) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+
+    let time: NaiveTime = NaiveTime::parse_from_str(time, "%H:%M:%S").map_err(|_| invalid())?;
+
+    datetime.0.parts |= BLPAPI_DATETIME_TIME_PART as u8;
+    datetime.0.hours = time.hour() as u8;
+    datetime.0.minutes = time.minute() as u8;
+    datetime.0.seconds = time.second() as u8;
+
+    let mut picoseconds: u64 = 0;
+
+    if let Some(fraction) = fraction {
+        if fraction.is_empty()
+            || fraction.len() > 12
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        picoseconds = format!("{:0<12}", fraction)
+            .parse()
+            .map_err(|_| invalid())?;
+
+        datetime.0.parts |= BLPAPI_DATETIME_MILLISECONDS_PART as u8;
+        datetime.0.milliSeconds = (picoseconds / PICOS_PER_MILLI) as u16;
+    }
+
+    if let Some(offset) = offset {
+        let offset: FixedOffset = offset.parse().map_err(|_| invalid())?;
+
+        datetime = datetime.with_offset(offset);
+    }
+
+    Ok((datetime, picoseconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetime_display() {
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+
+        assert_eq!(Datetime::from(date).to_string(), "2020-01-02");
+
+        let mut datetime: Datetime = date.and_hms_milli_opt(9, 30, 0, 250).unwrap().into();
+
+        assert_eq!(datetime.to_string(), "2020-01-02T09:30:00.250");
+
+        datetime.0.parts |= BLPAPI_DATETIME_OFFSET_PART as u8;
+        datetime.0.offset = -300;
+
+        assert_eq!(datetime.to_string(), "2020-01-02T09:30:00.250-05:00");
+
+        let midnight: Datetime = date.and_hms_opt(0, 0, 0).unwrap().into();
+
+        assert_eq!(format!("{:?}", midnight), "2020-01-02T00:00:00.000");
+    }
+
+    #[test]
+    fn datetime_conversions() {
+        let datetime: Datetime = "2020-01-02T09:30:00.250-05:00".parse().unwrap();
+
+        let offset: DateTime<FixedOffset> = datetime.try_into().unwrap();
+
+        assert_eq!(offset.to_rfc3339(), "2020-01-02T09:30:00.250-05:00");
+        assert_eq!(Datetime::from(offset).to_string(), datetime.to_string());
+
+        let date: NaiveDate = datetime.try_into().unwrap();
+
+        assert_eq!(date, NaiveDate::from_ymd_opt(2020, 1, 2).unwrap());
+
+        let time: Datetime = "09:30:00".parse().unwrap();
+
+        assert_eq!(time.to_string(), "09:30:00");
+        assert!(NaiveDate::try_from(time).is_err());
+        assert_eq!(
+            NaiveTime::try_from(time).unwrap(),
+            NaiveTime::from_hms_opt(9, 30, 0).unwrap()
+        );
+
+        let date: Datetime = "2020-01-02".parse().unwrap();
+
+        assert!(NaiveTime::try_from(date).is_err());
+        assert!(DateTime::<FixedOffset>::try_from(date).is_err());
+        assert!("2020-01-02T09:30:00.000123".parse::<Datetime>().is_err());
+        assert!("yesterday".parse::<Datetime>().is_err());
+    }
+
+    #[test]
+    fn high_precision_datetime() {
+        let datetime: HighPrecisionDatetime = "2020-01-02T09:30:00.123456789012Z".parse().unwrap();
+
+        assert_eq!(datetime.picoseconds(), 123_456_789_012);
+        assert_eq!(datetime.datetime().0.milliSeconds, 123);
+        assert_eq!(
+            datetime.to_string(),
+            "2020-01-02T09:30:00.123456789012+00:00"
+        );
+
+        let naive: NaiveDateTime = datetime.try_into().unwrap();
+
+        assert_eq!(naive.nanosecond(), 123_456_789);
+        assert_eq!(
+            HighPrecisionDatetime::from(naive).to_string(),
+            "2020-01-02T09:30:00.123456789"
+        );
+    }
 }
@@ -0,0 +1,380 @@
+use crate::{
+    datetime::Datetime,
+    element::{DataType, Element, GetValue},
+    Error,
+};
+use serde::de::{
+    self, value::StringDeserializer, Deserialize, DeserializeOwned, DeserializeSeed, Deserializer,
+    EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+
+impl de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Deserialize(msg.to_string().into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Datetime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let datetime: String = String::deserialize(deserializer)?;
+
+        datetime.parse().map_err(de::Error::custom)
+    }
+}
+
+pub fn from_element<T: DeserializeOwned>(element: &Element) -> Result<T, Error> {
+    T::deserialize(element)
+}
+
+impl<'de> Deserializer<'de> for &Element {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_array() {
+            return visitor.visit_seq(ArrayAccess {
+                element: self,
+                index: 0,
+                len: self.num_values(),
+            });
+        }
+
+        match self.datatype() {
+            DataType::Sequence => visitor.visit_map(SequenceAccess {
+                elements: self.elements().collect::<Vec<Element>>().into_iter(),
+                value: None,
+            }),
+            DataType::Choice => visitor.visit_map(SequenceAccess {
+                elements: vec![self.get_choice().ok_or_else(|| not_found(self))?].into_iter(),
+                value: None,
+            }),
+            _ if self.is_null() => visitor.visit_unit(),
+            _ => Value(self, 0).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.datatype() {
+            DataType::Choice => visitor.visit_enum(ChoiceAccess(
+                self.get_choice().ok_or_else(|| not_found(self))?,
+            )),
+            _ => Value(self, 0).deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct Value<'a>(&'a Element, usize);
+
+impl<'a> Value<'a> {
+    fn get<V: GetValue>(&self) -> Result<V, Error> {
+        self.0.get_at(self.1).ok_or_else(|| not_found(self.0))
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for Value<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0.datatype() {
+            DataType::Sequence | DataType::Choice => {
+                self.get::<Element>()?.deserialize_any(visitor)
+            }
+            _ if self.0.is_null_value(self.1) => visitor.visit_unit(),
+            DataType::Bool => visitor.visit_bool(self.get()?),
+            DataType::Char => visitor.visit_char(self.get::<i8>()? as u8 as char),
+            DataType::Byte | DataType::Int32 => visitor.visit_i32(self.get()?),
+            DataType::Int64 => visitor.visit_i64(self.get()?),
+            DataType::Float32 => visitor.visit_f32(self.get()?),
+            DataType::Float64 | DataType::Decimal => visitor.visit_f64(self.get()?),
+            DataType::String | DataType::ByteArray | DataType::Enumeration => {
+                visitor.visit_string(self.get()?)
+            }
+            DataType::Date | DataType::Time | DataType::Datetime => {
+                visitor.visit_string(self.get::<Datetime>()?.to_string())
+            }
+            DataType::CorrelationId | DataType::Unknown => visitor.visit_unit(),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.0.is_null_value(self.1) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0.datatype() {
+            DataType::Sequence | DataType::Choice => self
+                .get::<Element>()?
+                .deserialize_enum(name, variants, visitor),
+            _ => {
+                let variant: StringDeserializer<Error> = self.get::<String>()?.into_deserializer();
+
+                visitor.visit_enum(variant)
+            }
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ArrayAccess<'a> {
+    element: &'a Element,
+    index: usize,
+    len: usize,
+}
+
+impl<'de, 'a> SeqAccess<'de> for ArrayAccess<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+
+        let value: Value = Value(self.element, self.index);
+
+        self.index += 1;
+
+        seed.deserialize(value).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+struct SequenceAccess {
+    elements: std::vec::IntoIter<Element>,
+    value: Option<Element>,
+}
+
+impl<'de> MapAccess<'de> for SequenceAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        self.value = self.elements.next();
+
+        match self.value.as_ref() {
+            Some(element) => seed
+                .deserialize(element.string_name().into_deserializer())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let element: Element = self
+            .value
+            .take()
+            .ok_or_else(|| Error::Deserialize("value is missing".into()))?;
+
+        seed.deserialize(&element)
+    }
+}
+
+struct ChoiceAccess(Element);
+
+impl<'de> EnumAccess<'de> for ChoiceAccess {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+        let variant: StringDeserializer<Error> = self.0.string_name().into_deserializer();
+
+        Ok((seed.deserialize(variant)?, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ChoiceAccess {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(&self.0)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        (&self.0).deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        (&self.0).deserialize_map(visitor)
+    }
+}
+
+fn not_found(element: &Element) -> Error {
+    Error::NotFound(element.string_name())
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::{
+        event::Event,
+        mock::{self, MockElement, MockEvent, MockMessage, MockValue},
+    };
+    use chrono::NaiveDate;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct FieldData {
+        #[serde(rename = "PX_LAST")]
+        px_last: f64,
+        #[serde(rename = "ACTIVE", default)]
+        active: bool,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct Quote {
+        security: String,
+        date: NaiveDate,
+        closes: Vec<f64>,
+        bid: Option<f64>,
+        field_data: FieldData,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct SecurityData {
+        security: String,
+        field_data: FieldData,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    enum Response {
+        ResponseError(serde_json::Value),
+        SecurityData(Vec<SecurityData>),
+    }
+
+    fn message(event: &Event) -> Element {
+        event.messages().next().unwrap().element()
+    }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn deserialize_sequence() -> Result<(), Error> {
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        let event: Event = MockEvent::response()
+            .with_message(
+                MockMessage::new("Quote")
+                    .with("security", "IBM US Equity")
+                    .with("date", date)
+                    .with("closes", vec![140.5, 141.25])
+                    .with("bid", MockValue::Null)
+                    .with(
+                        "fieldData",
+                        MockElement::new()
+                            .with("PX_LAST", 140.5)
+                            .with("ACTIVE", true),
+                    ),
+            )
+            .build()?;
+
+        let element: Element = message(&event);
+        let quote: Quote = from_element(&element)?;
+
+        assert_eq!(
+            quote,
+            Quote {
+                security: "IBM US Equity".to_owned(),
+                date,
+                closes: vec![140.5, 141.25],
+                bid: None,
+                field_data: FieldData {
+                    px_last: 140.5,
+                    active: true,
+                },
+            }
+        );
+
+        let json: Quote = serde_json::from_value(element.to_json().unwrap()).unwrap();
+
+        assert_eq!(json, quote);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn deserialize_choice() -> Result<(), Error> {
+        let event: Event = MockEvent::response()
+            .with_message(MockMessage::new("ReferenceDataResponse").with(
+                "securityData",
+                vec![MockElement::new()
+                    .with("security", "IBM US Equity")
+                    .with("fieldData", MockElement::new().with("PX_LAST", 140.5))],
+            ))
+            .build_for(&mock::deserialize_service(mock::REFDATA_SCHEMA)?)?;
+
+        let response: Response = from_element(&message(&event))?;
+
+        assert_eq!(
+            response,
+            Response::SecurityData(vec![SecurityData {
+                security: "IBM US Equity".to_owned(),
+                field_data: FieldData {
+                    px_last: 140.5,
+                    active: false,
+                },
+            }])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn datetime() {
+        let datetime: Datetime = serde_json::from_str("\"2024-01-02T10:30:00.250\"").unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&datetime).unwrap(),
+            "\"2024-01-02T10:30:00.250\""
+        );
+    }
+}
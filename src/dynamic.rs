@@ -0,0 +1,1589 @@
+//! Runtime-loaded blpapi, enabled with the `dynamic` feature.
+//!
+//! Every function declared in `bindings.rs` is resolved from `libblpapi3_64` the first time
+//! it is used instead of being linked at build time. The library is looked up in the
+//! `BLPAPI_LIB` directory when set, and on the system library path otherwise. Call [`load`]
+//! or [`load_from`] early to get an [`Error::Library`] rather than a panic when the SDK is
+//! missing.
+
+#[cfg_attr(not(feature = "bindgen"), path = "bindings.rs")]
+#[cfg_attr(feature = "bindgen", path = "generated.rs")]
+mod raw;
+
+pub use raw::*;
+
+use crate::errors::Error;
+use libloading::{Library, Symbol};
+use std::ffi::{OsStr, OsString};
+use std::os::raw::{c_char, c_int, c_longlong, c_short, c_uint, c_ushort, c_void};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+static API: OnceLock<Api> = OnceLock::new();
+
+pub fn load() -> Result<(), Error> {
+    load_from(default_path())
+}
+
+pub fn load_from<P: AsRef<OsStr>>(path: P) -> Result<(), Error> {
+    if API.get().is_some() {
+        return Ok(());
+    }
+
+    let api: Api = unsafe { Api::load(path.as_ref())? };
+
+    let _ = API.set(api);
+
+    Ok(())
+}
+
+pub fn is_loaded() -> bool {
+    API.get().is_some()
+}
+
+fn default_path() -> OsString {
+    let filename: OsString = libloading::library_filename("blpapi3_64");
+
+    match std::env::var_os("BLPAPI_LIB") {
+        Some(dir) => PathBuf::from(dir).join(filename).into_os_string(),
+        None => filename,
+    }
+}
+
+fn api() -> &'static Api {
+    if let Err(e) = load() {
+        panic!("{}", e);
+    }
+
+    API.get().expect("blpapi library is loaded")
+}
+
+fn missing(name: &str) -> ! {
+    panic!(
+        "{} is not exported by the loaded blpapi library, the SDK may be too old...",
+        name
+    )
+}
+
+macro_rules! dynamic {
+    ($(fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;)*) => {
+        #[allow(non_snake_case)]
+        struct Api {
+            $($name: Option<unsafe extern "C" fn($($ty),*) $(-> $ret)?>,)*
+            _library: Library,
+        }
+
+        impl Api {
+            #[allow(non_snake_case)]
+            unsafe fn load(path: &OsStr) -> Result<Api, Error> {
+                let library: Library = Library::new(path).map_err(|e| Error::Library {
+                    path: path.to_string_lossy().into_owned(),
+                    source: Box::new(e),
+                })?;
+
+                $(
+                    let $name = library
+                        .get(concat!(stringify!($name), "\0").as_bytes())
+                        .ok()
+                        .map(|symbol: Symbol<unsafe extern "C" fn($($ty),*) $(-> $ret)?>| *symbol);
+                )*
+
+                log::debug!("Loaded blpapi from {}...", path.to_string_lossy());
+
+                Ok(Api {
+                    $($name,)*
+                    _library: library,
+                })
+            }
+        }
+
+        $(
+            /// # Safety
+            ///
+            /// Same contract as the blpapi function of the same name.
+            #[allow(non_snake_case)]
+            pub unsafe fn $name($($arg: $ty),*) $(-> $ret)? {
+                match api().$name {
+                    Some(f) => f($($arg),*),
+                    None => missing(stringify!($name)),
+                }
+            }
+
+            const _: unsafe extern "C" fn($($ty),*) $(-> $ret)? = raw::$name;
+        )*
+    };
+}
+
+dynamic! {
+    fn blpapi_Name_create(nameString: *const c_char) -> *mut blpapi_Name_t;
+    fn blpapi_Name_destroy(name: *mut blpapi_Name_t);
+    fn blpapi_Name_duplicate(src: *const blpapi_Name_t) -> *mut blpapi_Name_t;
+    fn blpapi_Name_equalsStr(name: *const blpapi_Name_t, string: *const c_char) -> c_int;
+    fn blpapi_Name_string(name: *const blpapi_Name_t) -> *const c_char;
+    fn blpapi_Name_length(name: *const blpapi_Name_t) -> usize;
+    fn blpapi_Name_findName(nameString: *const c_char) -> *mut blpapi_Name_t;
+    fn blpapi_getLastErrorDescription(resultCode: c_int) -> *const c_char;
+    fn blpapi_getErrorInfo(buffer: *mut blpapi_ErrorInfo_t, errorCode: c_int) -> c_int;
+    fn blpapi_TimePointUtil_nanosecondsBetween(
+        start: *const blpapi_TimePoint_t,
+        end: *const blpapi_TimePoint_t,
+    ) -> c_longlong;
+    fn blpapi_Datetime_compare(lhs: blpapi_Datetime_t, rhs: blpapi_Datetime_t) -> c_int;
+    fn blpapi_Datetime_print(
+        datetime: *const blpapi_Datetime_t,
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut c_void,
+        level: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_HighPrecisionDatetime_compare(
+        lhs: *const blpapi_HighPrecisionDatetime_t,
+        rhs: *const blpapi_HighPrecisionDatetime_t,
+    ) -> c_int;
+    fn blpapi_HighPrecisionDatetime_print(
+        datetime: *const blpapi_HighPrecisionDatetime_t,
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut c_void,
+        level: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_HighPrecisionDatetime_fromTimePoint(
+        datetime: *mut blpapi_HighPrecisionDatetime_t,
+        timePoint: *const blpapi_TimePoint_t,
+        offset: c_short,
+    ) -> c_int;
+    fn blpapi_Constant_setUserData(constant: *mut blpapi_Constant_t, userdata: *mut c_void);
+    fn blpapi_Constant_name(constant: *const blpapi_Constant_t) -> *mut blpapi_Name_t;
+    fn blpapi_Constant_description(constant: *const blpapi_Constant_t) -> *const c_char;
+    fn blpapi_Constant_status(constant: *const blpapi_Constant_t) -> c_int;
+    fn blpapi_Constant_datatype(constant: *const blpapi_Constant_t) -> c_int;
+    fn blpapi_Constant_getValueAsChar(
+        constant: *const blpapi_Constant_t,
+        buffer: *mut blpapi_Char_t,
+    ) -> c_int;
+    fn blpapi_Constant_getValueAsInt32(
+        constant: *const blpapi_Constant_t,
+        buffer: *mut blpapi_Int32_t,
+    ) -> c_int;
+    fn blpapi_Constant_getValueAsInt64(
+        constant: *const blpapi_Constant_t,
+        buffer: *mut blpapi_Int64_t,
+    ) -> c_int;
+    fn blpapi_Constant_getValueAsFloat32(
+        constant: *const blpapi_Constant_t,
+        buffer: *mut blpapi_Float32_t,
+    ) -> c_int;
+    fn blpapi_Constant_getValueAsFloat64(
+        constant: *const blpapi_Constant_t,
+        buffer: *mut blpapi_Float64_t,
+    ) -> c_int;
+    fn blpapi_Constant_getValueAsDatetime(
+        constant: *const blpapi_Constant_t,
+        buffer: *mut blpapi_Datetime_t,
+    ) -> c_int;
+    fn blpapi_Constant_getValueAsString(
+        constant: *const blpapi_Constant_t,
+        buffer: *mut *const c_char,
+    ) -> c_int;
+    fn blpapi_Constant_userData(constant: *const blpapi_Constant_t) -> *mut c_void;
+    fn blpapi_ConstantList_setUserData(constant: *mut blpapi_ConstantList_t, userdata: *mut c_void);
+    fn blpapi_ConstantList_name(list: *const blpapi_ConstantList_t) -> *mut blpapi_Name_t;
+    fn blpapi_ConstantList_description(list: *const blpapi_ConstantList_t) -> *const c_char;
+    fn blpapi_ConstantList_numConstants(list: *const blpapi_ConstantList_t) -> c_int;
+    fn blpapi_ConstantList_datatype(constant: *const blpapi_ConstantList_t) -> c_int;
+    fn blpapi_ConstantList_status(list: *const blpapi_ConstantList_t) -> c_int;
+    fn blpapi_ConstantList_getConstant(
+        constant: *const blpapi_ConstantList_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+    ) -> *mut blpapi_Constant_t;
+    fn blpapi_ConstantList_getConstantAt(
+        constant: *const blpapi_ConstantList_t,
+        index: usize,
+    ) -> *mut blpapi_Constant_t;
+    fn blpapi_ConstantList_userData(constant: *const blpapi_ConstantList_t) -> *mut c_void;
+    fn blpapi_SchemaElementDefinition_name(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> *mut blpapi_Name_t;
+    fn blpapi_SchemaElementDefinition_description(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> *const c_char;
+    fn blpapi_SchemaElementDefinition_status(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> c_int;
+    fn blpapi_SchemaElementDefinition_type(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> *mut blpapi_SchemaTypeDefinition_t;
+    fn blpapi_SchemaElementDefinition_numAlternateNames(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> usize;
+    fn blpapi_SchemaElementDefinition_getAlternateName(
+        field: *const blpapi_SchemaElementDefinition_t,
+        index: usize,
+    ) -> *mut blpapi_Name_t;
+    fn blpapi_SchemaElementDefinition_minValues(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> usize;
+    fn blpapi_SchemaElementDefinition_maxValues(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> usize;
+    fn blpapi_SchemaElementDefinition_print(
+        element: *const blpapi_SchemaElementDefinition_t,
+        streamWriter: blpapi_StreamWriter_t,
+        userStream: *mut c_void,
+        level: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_SchemaElementDefinition_setUserData(
+        field: *mut blpapi_SchemaElementDefinition_t,
+        userdata: *mut c_void,
+    );
+    fn blpapi_SchemaElementDefinition_userData(
+        field: *const blpapi_SchemaElementDefinition_t,
+    ) -> *mut c_void;
+    fn blpapi_SchemaTypeDefinition_name(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+    ) -> *mut blpapi_Name_t;
+    fn blpapi_SchemaTypeDefinition_description(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+    ) -> *const c_char;
+    fn blpapi_SchemaTypeDefinition_status(type_: *const blpapi_SchemaTypeDefinition_t) -> c_int;
+    fn blpapi_SchemaTypeDefinition_datatype(type_: *const blpapi_SchemaTypeDefinition_t) -> c_int;
+    fn blpapi_SchemaTypeDefinition_isComplexType(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+    ) -> c_int;
+    fn blpapi_SchemaTypeDefinition_isSimpleType(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+    ) -> c_int;
+    fn blpapi_SchemaTypeDefinition_isEnumerationType(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+    ) -> c_int;
+    fn blpapi_SchemaTypeDefinition_isComplex(type_: *const blpapi_SchemaTypeDefinition_t) -> c_int;
+    fn blpapi_SchemaTypeDefinition_isSimple(type_: *const blpapi_SchemaTypeDefinition_t) -> c_int;
+    fn blpapi_SchemaTypeDefinition_isEnumeration(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+    ) -> c_int;
+    fn blpapi_SchemaTypeDefinition_numElementDefinitions(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+    ) -> usize;
+    fn blpapi_SchemaTypeDefinition_getElementDefinition(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+    ) -> *mut blpapi_SchemaElementDefinition_t;
+    fn blpapi_SchemaTypeDefinition_getElementDefinitionAt(
+        type_: *const blpapi_SchemaTypeDefinition_t,
+        index: usize,
+    ) -> *mut blpapi_SchemaElementDefinition_t;
+    fn blpapi_SchemaTypeDefinition_print(
+        element: *const blpapi_SchemaTypeDefinition_t,
+        streamWriter: blpapi_StreamWriter_t,
+        userStream: *mut c_void,
+        level: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_SchemaTypeDefinition_setUserData(
+        element: *mut blpapi_SchemaTypeDefinition_t,
+        userdata: *mut c_void,
+    );
+    fn blpapi_SchemaTypeDefinition_userData(
+        element: *const blpapi_SchemaTypeDefinition_t,
+    ) -> *mut c_void;
+    fn blpapi_SchemaTypeDefinition_enumeration(
+        element: *const blpapi_SchemaTypeDefinition_t,
+    ) -> *mut blpapi_ConstantList_t;
+    fn blpapi_Element_name(element: *const blpapi_Element_t) -> *mut blpapi_Name_t;
+    fn blpapi_Element_nameString(element: *const blpapi_Element_t) -> *const c_char;
+    fn blpapi_Element_definition(
+        element: *const blpapi_Element_t,
+    ) -> *mut blpapi_SchemaElementDefinition_t;
+    fn blpapi_Element_datatype(element: *const blpapi_Element_t) -> c_int;
+    fn blpapi_Element_isComplexType(element: *const blpapi_Element_t) -> c_int;
+    fn blpapi_Element_isArray(element: *const blpapi_Element_t) -> c_int;
+    fn blpapi_Element_isReadOnly(element: *const blpapi_Element_t) -> c_int;
+    fn blpapi_Element_numValues(element: *const blpapi_Element_t) -> usize;
+    fn blpapi_Element_numElements(element: *const blpapi_Element_t) -> usize;
+    fn blpapi_Element_isNullValue(element: *const blpapi_Element_t, position: usize) -> c_int;
+    fn blpapi_Element_isNull(element: *const blpapi_Element_t) -> c_int;
+    fn blpapi_Element_print(
+        element: *const blpapi_Element_t,
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut c_void,
+        level: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_Element_getElementAt(
+        element: *const blpapi_Element_t,
+        result: *mut *mut blpapi_Element_t,
+        position: usize,
+    ) -> c_int;
+    fn blpapi_Element_getElement(
+        element: *const blpapi_Element_t,
+        result: *mut *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_Element_hasElement(
+        element: *const blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_Element_hasElementEx(
+        element: *const blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        excludeNullElements: c_int,
+        reserved: c_int,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsBool(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_Bool_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsChar(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_Char_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsInt32(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_Int32_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsInt64(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_Int64_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsFloat32(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_Float32_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsFloat64(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_Float64_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsString(
+        element: *const blpapi_Element_t,
+        buffer: *mut *const c_char,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsDatetime(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_Datetime_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsHighPrecisionDatetime(
+        element: *const blpapi_Element_t,
+        buffer: *mut blpapi_HighPrecisionDatetime_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsElement(
+        element: *const blpapi_Element_t,
+        buffer: *mut *mut blpapi_Element_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getValueAsName(
+        element: *const blpapi_Element_t,
+        buffer: *mut *mut blpapi_Name_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_getChoice(
+        element: *const blpapi_Element_t,
+        result: *mut *mut blpapi_Element_t,
+    ) -> c_int;
+    fn blpapi_Element_setValueBool(
+        element: *mut blpapi_Element_t,
+        value: blpapi_Bool_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueChar(
+        element: *mut blpapi_Element_t,
+        value: blpapi_Char_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueInt32(
+        element: *mut blpapi_Element_t,
+        value: blpapi_Int32_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueInt64(
+        element: *mut blpapi_Element_t,
+        value: blpapi_Int64_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueFloat32(
+        element: *mut blpapi_Element_t,
+        value: blpapi_Float32_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueFloat64(
+        element: *mut blpapi_Element_t,
+        value: blpapi_Float64_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueString(
+        element: *mut blpapi_Element_t,
+        value: *const c_char,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueDatetime(
+        element: *mut blpapi_Element_t,
+        value: *const blpapi_Datetime_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueHighPrecisionDatetime(
+        element: *mut blpapi_Element_t,
+        value: *const blpapi_HighPrecisionDatetime_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueFromElement(
+        element: *mut blpapi_Element_t,
+        value: *mut blpapi_Element_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setValueFromName(
+        element: *mut blpapi_Element_t,
+        value: *const blpapi_Name_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Element_setElementBool(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: blpapi_Bool_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementChar(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: blpapi_Char_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementInt32(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: blpapi_Int32_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementInt64(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: blpapi_Int64_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementFloat32(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: blpapi_Float32_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementFloat64(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: blpapi_Float64_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementString(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: *const c_char,
+    ) -> c_int;
+    fn blpapi_Element_setElementDatetime(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: *const blpapi_Datetime_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementHighPrecisionDatetime(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        value: *const blpapi_HighPrecisionDatetime_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementFromField(
+        element: *mut blpapi_Element_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+        sourcebuffer: *mut blpapi_Element_t,
+    ) -> c_int;
+    fn blpapi_Element_setElementFromName(
+        element: *mut blpapi_Element_t,
+        elementName: *const c_char,
+        name: *const blpapi_Name_t,
+        buffer: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_Element_appendElement(
+        element: *mut blpapi_Element_t,
+        appendedElement: *mut *mut blpapi_Element_t,
+    ) -> c_int;
+    fn blpapi_Element_setChoice(
+        element: *mut blpapi_Element_t,
+        resultElement: *mut *mut blpapi_Element_t,
+        nameCstr: *const c_char,
+        name: *const blpapi_Name_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Request_destroy(request: *mut blpapi_Request_t);
+    fn blpapi_Request_elements(request: *mut blpapi_Request_t) -> *mut blpapi_Element_t;
+    fn blpapi_Request_setPreferredRoute(
+        request: *mut blpapi_Request_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+    );
+    fn blpapi_Operation_name(service: *mut blpapi_Operation_t) -> *const c_char;
+    fn blpapi_Operation_description(service: *mut blpapi_Operation_t) -> *const c_char;
+    fn blpapi_Operation_requestDefinition(
+        service: *mut blpapi_Operation_t,
+        requestDefinition: *mut *mut blpapi_SchemaElementDefinition_t,
+    ) -> c_int;
+    fn blpapi_Operation_numResponseDefinitions(service: *mut blpapi_Operation_t) -> c_int;
+    fn blpapi_Operation_responseDefinition(
+        service: *mut blpapi_Operation_t,
+        responseDefinition: *mut *mut blpapi_SchemaElementDefinition_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Service_name(service: *mut blpapi_Service_t) -> *const c_char;
+    fn blpapi_Service_description(service: *mut blpapi_Service_t) -> *const c_char;
+    fn blpapi_Service_numOperations(service: *mut blpapi_Service_t) -> c_int;
+    fn blpapi_Service_numEventDefinitions(service: *mut blpapi_Service_t) -> c_int;
+    fn blpapi_Service_addRef(service: *mut blpapi_Service_t) -> c_int;
+    fn blpapi_Service_release(service: *mut blpapi_Service_t);
+    fn blpapi_Service_authorizationServiceName(service: *mut blpapi_Service_t) -> *const c_char;
+    fn blpapi_Service_getOperation(
+        service: *mut blpapi_Service_t,
+        operation: *mut *mut blpapi_Operation_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_Service_getOperationAt(
+        service: *mut blpapi_Service_t,
+        operation: *mut *mut blpapi_Operation_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Service_getEventDefinition(
+        service: *mut blpapi_Service_t,
+        result: *mut *mut blpapi_SchemaElementDefinition_t,
+        nameString: *const c_char,
+        name: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_Service_getEventDefinitionAt(
+        service: *mut blpapi_Service_t,
+        result: *mut *mut blpapi_SchemaElementDefinition_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Service_createRequest(
+        service: *mut blpapi_Service_t,
+        request: *mut *mut blpapi_Request_t,
+        operation: *const c_char,
+    ) -> c_int;
+    fn blpapi_Service_createAuthorizationRequest(
+        service: *mut blpapi_Service_t,
+        request: *mut *mut blpapi_Request_t,
+        operation: *const c_char,
+    ) -> c_int;
+    fn blpapi_Service_createPublishEvent(
+        service: *mut blpapi_Service_t,
+        event: *mut *mut blpapi_Event_t,
+    ) -> c_int;
+    fn blpapi_Service_createAdminEvent(
+        service: *mut blpapi_Service_t,
+        event: *mut *mut blpapi_Event_t,
+    ) -> c_int;
+    fn blpapi_Service_createResponseEvent(
+        service: *mut blpapi_Service_t,
+        correlationId: *const blpapi_CorrelationId_t,
+        event: *mut *mut blpapi_Event_t,
+    ) -> c_int;
+    fn blpapi_Service_print(
+        service: *const blpapi_Service_t,
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut c_void,
+        level: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_Message_messageType(message: *const blpapi_Message_t) -> *mut blpapi_Name_t;
+    fn blpapi_Message_typeString(message: *const blpapi_Message_t) -> *const c_char;
+    fn blpapi_Message_topicName(message: *const blpapi_Message_t) -> *const c_char;
+    fn blpapi_Message_service(message: *const blpapi_Message_t) -> *mut blpapi_Service_t;
+    fn blpapi_Message_numCorrelationIds(message: *const blpapi_Message_t) -> c_int;
+    fn blpapi_Message_correlationId(
+        message: *const blpapi_Message_t,
+        index: usize,
+    ) -> blpapi_CorrelationId_t;
+    fn blpapi_Message_elements(message: *const blpapi_Message_t) -> *mut blpapi_Element_t;
+    fn blpapi_Message_privateData(
+        message: *const blpapi_Message_t,
+        size: *mut usize,
+    ) -> *const c_char;
+    fn blpapi_Message_fragmentType(message: *const blpapi_Message_t) -> c_int;
+    fn blpapi_Message_recapType(message: *const blpapi_Message_t) -> c_int;
+    fn blpapi_Message_print(
+        message: *const blpapi_Message_t,
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut c_void,
+        indentLevel: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_Message_addRef(message: *const blpapi_Message_t) -> c_int;
+    fn blpapi_Message_release(message: *const blpapi_Message_t) -> c_int;
+    fn blpapi_Message_timeReceived(
+        message: *const blpapi_Message_t,
+        timeReceived: *mut blpapi_TimePoint_t,
+    ) -> c_int;
+    fn blpapi_Event_eventType(event: *const blpapi_Event_t) -> c_int;
+    fn blpapi_Event_addRef(event: *const blpapi_Event_t) -> c_int;
+    fn blpapi_Event_release(event: *const blpapi_Event_t) -> c_int;
+    fn blpapi_EventQueue_create() -> *mut blpapi_EventQueue_t;
+    fn blpapi_EventQueue_destroy(eventQueue: *mut blpapi_EventQueue_t) -> c_int;
+    fn blpapi_EventQueue_nextEvent(
+        eventQueue: *mut blpapi_EventQueue_t,
+        timeout: c_int,
+    ) -> *mut blpapi_Event_t;
+    fn blpapi_EventQueue_purge(eventQueue: *mut blpapi_EventQueue_t) -> c_int;
+    fn blpapi_EventQueue_tryNextEvent(
+        eventQueue: *mut blpapi_EventQueue_t,
+        eventPointer: *mut *mut blpapi_Event_t,
+    ) -> c_int;
+    fn blpapi_MessageIterator_create(event: *const blpapi_Event_t) -> *mut blpapi_MessageIterator_t;
+    fn blpapi_MessageIterator_destroy(iterator: *mut blpapi_MessageIterator_t);
+    fn blpapi_MessageIterator_next(
+        iterator: *mut blpapi_MessageIterator_t,
+        result: *mut *mut blpapi_Message_t,
+    ) -> c_int;
+    fn blpapi_EventDispatcher_create(numDispatcherThreads: usize) -> *mut blpapi_EventDispatcher_t;
+    fn blpapi_EventDispatcher_destroy(handle: *mut blpapi_EventDispatcher_t);
+    fn blpapi_EventDispatcher_start(handle: *mut blpapi_EventDispatcher_t) -> c_int;
+    fn blpapi_EventDispatcher_stop(handle: *mut blpapi_EventDispatcher_t, async_: c_int) -> c_int;
+    fn blpapi_EventDispatcher_dispatchEvents(handle: *mut blpapi_EventDispatcher_t) -> c_int;
+    fn blpapi_Identity_release(handle: *mut blpapi_Identity_t);
+    fn blpapi_Identity_addRef(handle: *mut blpapi_Identity_t) -> c_int;
+    fn blpapi_Identity_hasEntitlements(
+        handle: *const blpapi_Identity_t,
+        service: *const blpapi_Service_t,
+        eidElement: *const blpapi_Element_t,
+        entitlementIds: *const c_int,
+        numEntitlements: usize,
+        failedEntitlements: *mut c_int,
+        failedEntitlementsCount: *mut c_int,
+    ) -> c_int;
+    fn blpapi_Identity_isAuthorized(
+        handle: *const blpapi_Identity_t,
+        service: *const blpapi_Service_t,
+    ) -> c_int;
+    fn blpapi_Identity_getSeatType(handle: *const blpapi_Identity_t, seatType: *mut c_int) -> c_int;
+    fn blpapi_UserHandle_release(handle: *mut blpapi_UserHandle_t);
+    fn blpapi_UserHandle_addRef(handle: *mut blpapi_UserHandle_t) -> c_int;
+    fn blpapi_UserHandle_hasEntitlements(
+        handle: *const blpapi_UserHandle_t,
+        service: *const blpapi_Service_t,
+        eidElement: *const blpapi_Element_t,
+        entitlementIds: *const c_int,
+        numEntitlements: usize,
+        failedEntitlements: *mut c_int,
+        failedEntitlementsCount: *mut c_int,
+    ) -> c_int;
+    fn blpapi_AbstractSession_cancel(
+        session: *mut blpapi_AbstractSession_t,
+        correlationIds: *const blpapi_CorrelationId_t,
+        numCorrelationIds: usize,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_AbstractSession_sendAuthorizationRequest(
+        session: *mut blpapi_AbstractSession_t,
+        request: *const blpapi_Request_t,
+        identity: *mut blpapi_Identity_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+        eventQueue: *mut blpapi_EventQueue_t,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_AbstractSession_openService(
+        session: *mut blpapi_AbstractSession_t,
+        serviceIdentifier: *const c_char,
+    ) -> c_int;
+    fn blpapi_AbstractSession_openServiceAsync(
+        session: *mut blpapi_AbstractSession_t,
+        serviceIdentifier: *const c_char,
+        correlationId: *mut blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_AbstractSession_generateToken(
+        session: *mut blpapi_AbstractSession_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+        eventQueue: *mut blpapi_EventQueue_t,
+    ) -> c_int;
+    fn blpapi_AbstractSession_generateManualToken(
+        session: *mut blpapi_AbstractSession_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+        user: *const c_char,
+        manualIp: *const c_char,
+        eventQueue: *mut blpapi_EventQueue_t,
+    ) -> c_int;
+    fn blpapi_AbstractSession_getService(
+        session: *mut blpapi_AbstractSession_t,
+        service: *mut *mut blpapi_Service_t,
+        serviceIdentifier: *const c_char,
+    ) -> c_int;
+    fn blpapi_AbstractSession_createIdentity(
+        session: *mut blpapi_AbstractSession_t,
+    ) -> *mut blpapi_Identity_t;
+    fn blpapi_Logging_registerCallback(
+        callback: blpapi_Logging_Func_t,
+        thresholdSeverity: blpapi_Logging_Severity_t,
+    ) -> c_int;
+    fn blpapi_Logging_logTestMessage(severity: blpapi_Logging_Severity_t);
+    fn blpapi_ResolutionList_extractAttributeFromResolutionSuccess(
+        message: *const blpapi_Message_t,
+        attribute: *const blpapi_Name_t,
+    ) -> *mut blpapi_Element_t;
+    fn blpapi_ResolutionList_create(
+        from: *mut blpapi_ResolutionList_t,
+    ) -> *mut blpapi_ResolutionList_t;
+    fn blpapi_ResolutionList_destroy(list: *mut blpapi_ResolutionList_t);
+    fn blpapi_ResolutionList_add(
+        list: *mut blpapi_ResolutionList_t,
+        topic: *const c_char,
+        correlationId: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_ResolutionList_addFromMessage(
+        list: *mut blpapi_ResolutionList_t,
+        topic: *const blpapi_Message_t,
+        correlationId: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_ResolutionList_addAttribute(
+        list: *mut blpapi_ResolutionList_t,
+        name: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_ResolutionList_correlationIdAt(
+        list: *const blpapi_ResolutionList_t,
+        result: *mut blpapi_CorrelationId_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_ResolutionList_topicString(
+        list: *const blpapi_ResolutionList_t,
+        topic: *mut *const c_char,
+        id: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_ResolutionList_topicStringAt(
+        list: *const blpapi_ResolutionList_t,
+        topic: *mut *const c_char,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_ResolutionList_status(
+        list: *const blpapi_ResolutionList_t,
+        status: *mut c_int,
+        id: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_ResolutionList_statusAt(
+        list: *const blpapi_ResolutionList_t,
+        status: *mut c_int,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_ResolutionList_attribute(
+        list: *const blpapi_ResolutionList_t,
+        element: *mut *mut blpapi_Element_t,
+        attribute: *const blpapi_Name_t,
+        id: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_ResolutionList_attributeAt(
+        list: *const blpapi_ResolutionList_t,
+        element: *mut *mut blpapi_Element_t,
+        attribute: *const blpapi_Name_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_ResolutionList_message(
+        list: *const blpapi_ResolutionList_t,
+        element: *mut *mut blpapi_Message_t,
+        id: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_ResolutionList_messageAt(
+        list: *const blpapi_ResolutionList_t,
+        element: *mut *mut blpapi_Message_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_ResolutionList_size(list: *const blpapi_ResolutionList_t) -> c_int;
+    fn blpapi_TopicList_create(from: *mut blpapi_TopicList_t) -> *mut blpapi_TopicList_t;
+    fn blpapi_TopicList_destroy(list: *mut blpapi_TopicList_t);
+    fn blpapi_TopicList_add(
+        list: *mut blpapi_TopicList_t,
+        topic: *const c_char,
+        correlationId: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_TopicList_addFromMessage(
+        list: *mut blpapi_TopicList_t,
+        topic: *const blpapi_Message_t,
+        correlationId: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_TopicList_correlationIdAt(
+        list: *const blpapi_TopicList_t,
+        result: *mut blpapi_CorrelationId_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_TopicList_topicString(
+        list: *const blpapi_TopicList_t,
+        topic: *mut *const c_char,
+        id: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_TopicList_topicStringAt(
+        list: *const blpapi_TopicList_t,
+        topic: *mut *const c_char,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_TopicList_status(
+        list: *const blpapi_TopicList_t,
+        status: *mut c_int,
+        id: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_TopicList_statusAt(
+        list: *const blpapi_TopicList_t,
+        status: *mut c_int,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_TopicList_message(
+        list: *const blpapi_TopicList_t,
+        element: *mut *mut blpapi_Message_t,
+        id: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_TopicList_messageAt(
+        list: *const blpapi_TopicList_t,
+        element: *mut *mut blpapi_Message_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_TopicList_size(list: *const blpapi_TopicList_t) -> c_int;
+    fn blpapi_RequestTemplate_addRef(requestTemplate: *const blpapi_RequestTemplate_t) -> c_int;
+    fn blpapi_RequestTemplate_release(requestTemplate: *const blpapi_RequestTemplate_t) -> c_int;
+    fn blpapi_TlsOptions_create() -> *mut blpapi_TlsOptions_t;
+    fn blpapi_TlsOptions_duplicate(
+        parameters: *const blpapi_TlsOptions_t,
+    ) -> *mut blpapi_TlsOptions_t;
+    fn blpapi_TlsOptions_copy(lhs: *mut blpapi_TlsOptions_t, rhs: *const blpapi_TlsOptions_t);
+    fn blpapi_TlsOptions_destroy(parameters: *mut blpapi_TlsOptions_t);
+    fn blpapi_TlsOptions_createFromFiles(
+        clientCredentialsFileName: *const c_char,
+        clientCredentialsPassword: *const c_char,
+        trustedCertificatesFileName: *const c_char,
+    ) -> *mut blpapi_TlsOptions_t;
+    fn blpapi_TlsOptions_createFromBlobs(
+        clientCredentialsRawData: *const c_char,
+        clientCredentialsRawDataLength: c_int,
+        clientCredentialsPassword: *const c_char,
+        trustedCertificatesRawData: *const c_char,
+        trustedCertificatesRawDataLength: c_int,
+    ) -> *mut blpapi_TlsOptions_t;
+    fn blpapi_TlsOptions_setTlsHandshakeTimeoutMs(
+        paramaters: *mut blpapi_TlsOptions_t,
+        tlsHandshakeTimeoutMs: c_int,
+    );
+    fn blpapi_TlsOptions_setCrlFetchTimeoutMs(
+        paramaters: *mut blpapi_TlsOptions_t,
+        crlFetchTimeoutMs: c_int,
+    );
+    fn blpapi_SessionOptions_create() -> *mut blpapi_SessionOptions_t;
+    fn blpapi_SessionOptions_duplicate(
+        parameters: *const blpapi_SessionOptions_t,
+    ) -> *mut blpapi_SessionOptions_t;
+    fn blpapi_SessionOptions_copy(
+        lhs: *mut blpapi_SessionOptions_t,
+        rhs: *const blpapi_SessionOptions_t,
+    );
+    fn blpapi_SessionOptions_destroy(parameters: *mut blpapi_SessionOptions_t);
+    fn blpapi_SessionOptions_setServerHost(
+        parameters: *mut blpapi_SessionOptions_t,
+        serverHost: *const c_char,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setServerPort(
+        parameters: *mut blpapi_SessionOptions_t,
+        serverPort: c_ushort,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setServerAddress(
+        parameters: *mut blpapi_SessionOptions_t,
+        serverHost: *const c_char,
+        serverPort: c_ushort,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_SessionOptions_removeServerAddress(
+        parameters: *mut blpapi_SessionOptions_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setConnectTimeout(
+        parameters: *mut blpapi_SessionOptions_t,
+        timeoutInMilliseconds: c_uint,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setDefaultServices(
+        parameters: *mut blpapi_SessionOptions_t,
+        defaultServices: *const c_char,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setDefaultSubscriptionService(
+        parameters: *mut blpapi_SessionOptions_t,
+        serviceIdentifier: *const c_char,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setDefaultTopicPrefix(
+        parameters: *mut blpapi_SessionOptions_t,
+        prefix: *const c_char,
+    );
+    fn blpapi_SessionOptions_setAllowMultipleCorrelatorsPerMsg(
+        parameters: *mut blpapi_SessionOptions_t,
+        allowMultipleCorrelatorsPerMsg: c_int,
+    );
+    fn blpapi_SessionOptions_setClientMode(
+        parameters: *mut blpapi_SessionOptions_t,
+        clientMode: c_int,
+    );
+    fn blpapi_SessionOptions_setMaxPendingRequests(
+        parameters: *mut blpapi_SessionOptions_t,
+        maxPendingRequests: c_int,
+    );
+    fn blpapi_SessionOptions_setAutoRestartOnDisconnection(
+        parameters: *mut blpapi_SessionOptions_t,
+        autoRestart: c_int,
+    );
+    fn blpapi_SessionOptions_setAutoRestart(
+        parameters: *mut blpapi_SessionOptions_t,
+        autoRestart: c_int,
+    );
+    fn blpapi_SessionOptions_setAuthenticationOptions(
+        parameters: *mut blpapi_SessionOptions_t,
+        authOptions: *const c_char,
+    );
+    fn blpapi_SessionOptions_setNumStartAttempts(
+        parameters: *mut blpapi_SessionOptions_t,
+        numStartAttempts: c_int,
+    );
+    fn blpapi_SessionOptions_setMaxEventQueueSize(
+        parameters: *mut blpapi_SessionOptions_t,
+        maxEventQueueSize: usize,
+    );
+    fn blpapi_SessionOptions_setSlowConsumerWarningHiWaterMark(
+        parameters: *mut blpapi_SessionOptions_t,
+        hiWaterMark: f32,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setSlowConsumerWarningLoWaterMark(
+        parameters: *mut blpapi_SessionOptions_t,
+        loWaterMark: f32,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setDefaultKeepAliveInactivityTime(
+        parameters: *mut blpapi_SessionOptions_t,
+        inactivityMsecs: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setDefaultKeepAliveResponseTimeout(
+        parameters: *mut blpapi_SessionOptions_t,
+        timeoutMsecs: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setKeepAliveEnabled(
+        parameters: *mut blpapi_SessionOptions_t,
+        isEnabled: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setRecordSubscriptionDataReceiveTimes(
+        parameters: *mut blpapi_SessionOptions_t,
+        shouldRecord: c_int,
+    );
+    fn blpapi_SessionOptions_setServiceCheckTimeout(
+        paramaters: *mut blpapi_SessionOptions_t,
+        timeoutMsecs: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setServiceDownloadTimeout(
+        paramaters: *mut blpapi_SessionOptions_t,
+        timeoutMsecs: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setTlsOptions(
+        paramaters: *mut blpapi_SessionOptions_t,
+        tlsOptions: *const blpapi_TlsOptions_t,
+    );
+    fn blpapi_SessionOptions_setFlushPublishedEventsTimeout(
+        paramaters: *mut blpapi_SessionOptions_t,
+        timeoutMsecs: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_serverHost(parameters: *mut blpapi_SessionOptions_t) -> *const c_char;
+    fn blpapi_SessionOptions_serverPort(parameters: *mut blpapi_SessionOptions_t) -> c_uint;
+    fn blpapi_SessionOptions_numServerAddresses(parameters: *mut blpapi_SessionOptions_t) -> c_int;
+    fn blpapi_SessionOptions_getServerAddress(
+        parameters: *mut blpapi_SessionOptions_t,
+        serverHost: *mut *const c_char,
+        serverPort: *mut c_ushort,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_SessionOptions_connectTimeout(parameters: *mut blpapi_SessionOptions_t) -> c_uint;
+    fn blpapi_SessionOptions_defaultServices(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> *const c_char;
+    fn blpapi_SessionOptions_defaultSubscriptionService(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> *const c_char;
+    fn blpapi_SessionOptions_defaultTopicPrefix(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> *const c_char;
+    fn blpapi_SessionOptions_allowMultipleCorrelatorsPerMsg(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SessionOptions_clientMode(parameters: *mut blpapi_SessionOptions_t) -> c_int;
+    fn blpapi_SessionOptions_maxPendingRequests(parameters: *mut blpapi_SessionOptions_t) -> c_int;
+    fn blpapi_SessionOptions_autoRestartOnDisconnection(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SessionOptions_autoRestart(parameters: *mut blpapi_SessionOptions_t) -> c_int;
+    fn blpapi_SessionOptions_authenticationOptions(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> *const c_char;
+    fn blpapi_SessionOptions_numStartAttempts(parameters: *mut blpapi_SessionOptions_t) -> c_int;
+    fn blpapi_SessionOptions_maxEventQueueSize(parameters: *mut blpapi_SessionOptions_t) -> usize;
+    fn blpapi_SessionOptions_slowConsumerWarningHiWaterMark(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> f32;
+    fn blpapi_SessionOptions_slowConsumerWarningLoWaterMark(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> f32;
+    fn blpapi_SessionOptions_defaultKeepAliveInactivityTime(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SessionOptions_defaultKeepAliveResponseTimeout(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SessionOptions_keepAliveEnabled(parameters: *mut blpapi_SessionOptions_t) -> c_int;
+    fn blpapi_SessionOptions_recordSubscriptionDataReceiveTimes(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SessionOptions_serviceCheckTimeout(parameters: *mut blpapi_SessionOptions_t) -> c_int;
+    fn blpapi_SessionOptions_serviceDownloadTimeout(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SessionOptions_print(
+        parameters: *mut blpapi_SessionOptions_t,
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut c_void,
+        indentLevel: c_int,
+        spacesPerLevel: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_flushPublishedEventsTimeout(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SessionOptions_setBandwidthSaveModeDisabled(
+        parameters: *mut blpapi_SessionOptions_t,
+        disableBandwidthSaveMode: c_int,
+    ) -> c_int;
+    fn blpapi_SessionOptions_bandwidthSaveModeDisabled(
+        parameters: *mut blpapi_SessionOptions_t,
+    ) -> c_int;
+    fn blpapi_SubscriptionList_create() -> *mut blpapi_SubscriptionList_t;
+    fn blpapi_SubscriptionList_destroy(list: *mut blpapi_SubscriptionList_t);
+    fn blpapi_SubscriptionList_add(
+        list: *mut blpapi_SubscriptionList_t,
+        subscriptionString: *const c_char,
+        correlationId: *const blpapi_CorrelationId_t,
+        fields: *mut *const c_char,
+        options: *mut *const c_char,
+        numfields: usize,
+        numOptions: usize,
+    ) -> c_int;
+    fn blpapi_SubscriptionList_addResolved(
+        list: *mut blpapi_SubscriptionList_t,
+        subscriptionString: *const c_char,
+        correlationId: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_SubscriptionList_clear(list: *mut blpapi_SubscriptionList_t) -> c_int;
+    fn blpapi_SubscriptionList_append(
+        dest: *mut blpapi_SubscriptionList_t,
+        src: *const blpapi_SubscriptionList_t,
+    ) -> c_int;
+    fn blpapi_SubscriptionList_size(list: *const blpapi_SubscriptionList_t) -> c_int;
+    fn blpapi_SubscriptionList_correlationIdAt(
+        list: *const blpapi_SubscriptionList_t,
+        result: *mut blpapi_CorrelationId_t,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_SubscriptionList_topicStringAt(
+        list: *mut blpapi_SubscriptionList_t,
+        result: *mut *const c_char,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_SubscriptionList_isResolvedAt(
+        list: *mut blpapi_SubscriptionList_t,
+        result: *mut c_int,
+        index: usize,
+    ) -> c_int;
+    fn blpapi_Session_create(
+        parameters: *mut blpapi_SessionOptions_t,
+        handler: blpapi_EventHandler_t,
+        dispatcher: *mut blpapi_EventDispatcher_t,
+        userData: *mut c_void,
+    ) -> *mut blpapi_Session_t;
+    fn blpapi_Session_destroy(session: *mut blpapi_Session_t);
+    fn blpapi_Session_start(session: *mut blpapi_Session_t) -> c_int;
+    fn blpapi_Session_startAsync(session: *mut blpapi_Session_t) -> c_int;
+    fn blpapi_Session_stop(session: *mut blpapi_Session_t) -> c_int;
+    fn blpapi_Session_stopAsync(session: *mut blpapi_Session_t) -> c_int;
+    fn blpapi_Session_nextEvent(
+        session: *mut blpapi_Session_t,
+        eventPointer: *mut *mut blpapi_Event_t,
+        timeoutInMilliseconds: c_uint,
+    ) -> c_int;
+    fn blpapi_Session_tryNextEvent(
+        session: *mut blpapi_Session_t,
+        eventPointer: *mut *mut blpapi_Event_t,
+    ) -> c_int;
+    fn blpapi_Session_subscribe(
+        session: *mut blpapi_Session_t,
+        subscriptionList: *const blpapi_SubscriptionList_t,
+        handle: *const blpapi_Identity_t,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_Session_resubscribe(
+        session: *mut blpapi_Session_t,
+        resubscriptionList: *const blpapi_SubscriptionList_t,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_Session_resubscribeWithId(
+        session: *mut blpapi_Session_t,
+        resubscriptionList: *const blpapi_SubscriptionList_t,
+        resubscriptionId: c_int,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_Session_unsubscribe(
+        session: *mut blpapi_Session_t,
+        unsubscriptionList: *const blpapi_SubscriptionList_t,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_Session_cancel(
+        session: *mut blpapi_Session_t,
+        correlationIds: *const blpapi_CorrelationId_t,
+        numCorrelationIds: usize,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_Session_setStatusCorrelationId(
+        session: *mut blpapi_Session_t,
+        service: *const blpapi_Service_t,
+        identity: *const blpapi_Identity_t,
+        correlationId: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_Session_sendRequest(
+        session: *mut blpapi_Session_t,
+        request: *const blpapi_Request_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+        identity: *mut blpapi_Identity_t,
+        eventQueue: *mut blpapi_EventQueue_t,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_Session_sendRequestTemplate(
+        session: *mut blpapi_Session_t,
+        requestTemplate: *const blpapi_RequestTemplate_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_Session_createSnapshotRequestTemplate(
+        requestTemplate: *mut *mut blpapi_RequestTemplate_t,
+        session: *mut blpapi_Session_t,
+        subscriptionString: *const c_char,
+        identity: *const blpapi_Identity_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_Session_sendAuthorizationRequest(
+        session: *mut blpapi_Session_t,
+        request: *const blpapi_Request_t,
+        identity: *mut blpapi_Identity_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+        eventQueue: *mut blpapi_EventQueue_t,
+        requestLabel: *const c_char,
+        requestLabelLen: c_int,
+    ) -> c_int;
+    fn blpapi_Session_openService(
+        session: *mut blpapi_Session_t,
+        serviceName: *const c_char,
+    ) -> c_int;
+    fn blpapi_Session_openServiceAsync(
+        session: *mut blpapi_Session_t,
+        serviceName: *const c_char,
+        correlationId: *mut blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_Session_generateToken(
+        session: *mut blpapi_Session_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+        eventQueue: *mut blpapi_EventQueue_t,
+    ) -> c_int;
+    fn blpapi_Session_getService(
+        session: *mut blpapi_Session_t,
+        service: *mut *mut blpapi_Service_t,
+        serviceName: *const c_char,
+    ) -> c_int;
+    fn blpapi_Session_createUserHandle(session: *mut blpapi_Session_t) -> *mut blpapi_UserHandle_t;
+    fn blpapi_Session_createIdentity(session: *mut blpapi_Session_t) -> *mut blpapi_Identity_t;
+    fn blpapi_Session_getAbstractSession(
+        session: *mut blpapi_Session_t,
+    ) -> *mut blpapi_AbstractSession_t;
+    fn blpapi_SubscriptionItr_create(
+        session: *mut blpapi_Session_t,
+    ) -> *mut blpapi_SubscriptionIterator_t;
+    fn blpapi_SubscriptionItr_destroy(iterator: *mut blpapi_SubscriptionIterator_t);
+    fn blpapi_SubscriptionItr_next(
+        iterator: *mut blpapi_SubscriptionIterator_t,
+        subscriptionString: *mut *const c_char,
+        correlationId: *mut blpapi_CorrelationId_t,
+        status: *mut c_int,
+    ) -> c_int;
+    fn blpapi_SubscriptionItr_isValid(iterator: *const blpapi_SubscriptionIterator_t) -> c_int;
+    fn blpapi_getVersionInfo(
+        majorVersion: *mut c_int,
+        minorVersion: *mut c_int,
+        patchVersion: *mut c_int,
+        buildVersion: *mut c_int,
+    );
+    fn blpapi_getVersionIdentifier() -> *const c_char;
+    fn blpapi_Topic_create(from: *mut blpapi_Topic_t) -> *mut blpapi_Topic_t;
+    fn blpapi_Topic_destroy(victim: *mut blpapi_Topic_t);
+    fn blpapi_Topic_compare(lhs: *const blpapi_Topic_t, rhs: *const blpapi_Topic_t) -> c_int;
+    fn blpapi_Topic_service(topic: *const blpapi_Topic_t) -> *mut blpapi_Service_t;
+    fn blpapi_Topic_isActive(topic: *const blpapi_Topic_t) -> c_int;
+    fn blpapi_ProviderSession_create(
+        parameters: *mut blpapi_SessionOptions_t,
+        handler: blpapi_ProviderEventHandler_t,
+        dispatcher: *mut blpapi_EventDispatcher_t,
+        userData: *mut c_void,
+    ) -> *mut blpapi_ProviderSession_t;
+    fn blpapi_ProviderSession_destroy(session: *mut blpapi_ProviderSession_t);
+    fn blpapi_ProviderSession_start(session: *mut blpapi_ProviderSession_t) -> c_int;
+    fn blpapi_ProviderSession_startAsync(session: *mut blpapi_ProviderSession_t) -> c_int;
+    fn blpapi_ProviderSession_stop(session: *mut blpapi_ProviderSession_t) -> c_int;
+    fn blpapi_ProviderSession_stopAsync(session: *mut blpapi_ProviderSession_t) -> c_int;
+    fn blpapi_ProviderSession_nextEvent(
+        session: *mut blpapi_ProviderSession_t,
+        eventPointer: *mut *mut blpapi_Event_t,
+        timeoutInMilliseconds: c_uint,
+    ) -> c_int;
+    fn blpapi_ProviderSession_tryNextEvent(
+        session: *mut blpapi_ProviderSession_t,
+        eventPointer: *mut *mut blpapi_Event_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_registerService(
+        session: *mut blpapi_ProviderSession_t,
+        serviceName: *const c_char,
+        identity: *const blpapi_Identity_t,
+        registrationOptions: *mut blpapi_ServiceRegistrationOptions_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_activateSubServiceCodeRange(
+        session: *mut blpapi_ProviderSession_t,
+        serviceName: *const c_char,
+        begin: c_int,
+        end: c_int,
+        priority: c_int,
+    ) -> c_int;
+    fn blpapi_ProviderSession_deactivateSubServiceCodeRange(
+        session: *mut blpapi_ProviderSession_t,
+        serviceName: *const c_char,
+        begin: c_int,
+        end: c_int,
+    ) -> c_int;
+    fn blpapi_ProviderSession_registerServiceAsync(
+        session: *mut blpapi_ProviderSession_t,
+        serviceName: *const c_char,
+        identity: *const blpapi_Identity_t,
+        correlationId: *mut blpapi_CorrelationId_t,
+        registrationOptions: *mut blpapi_ServiceRegistrationOptions_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_deregisterService(
+        session: *mut blpapi_ProviderSession_t,
+        serviceName: *const c_char,
+    ) -> c_int;
+    fn blpapi_ProviderSession_resolve(
+        session: *mut blpapi_ProviderSession_t,
+        resolutionList: *mut blpapi_ResolutionList_t,
+        resolveMode: c_int,
+        identity: *const blpapi_Identity_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_resolveAsync(
+        session: *mut blpapi_ProviderSession_t,
+        resolutionList: *const blpapi_ResolutionList_t,
+        resolveMode: c_int,
+        identity: *const blpapi_Identity_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_createTopics(
+        session: *mut blpapi_ProviderSession_t,
+        topicList: *mut blpapi_TopicList_t,
+        resolveMode: c_int,
+        identity: *const blpapi_Identity_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_createTopicsAsync(
+        session: *mut blpapi_ProviderSession_t,
+        topicList: *const blpapi_TopicList_t,
+        resolveMode: c_int,
+        identity: *const blpapi_Identity_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_getTopic(
+        session: *mut blpapi_ProviderSession_t,
+        message: *const blpapi_Message_t,
+        topic: *mut *mut blpapi_Topic_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_createTopic(
+        session: *mut blpapi_ProviderSession_t,
+        message: *const blpapi_Message_t,
+        topic: *mut *mut blpapi_Topic_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_createServiceStatusTopic(
+        session: *mut blpapi_ProviderSession_t,
+        service: *const blpapi_Service_t,
+        topic: *mut *mut blpapi_Topic_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_deleteTopics(
+        session: *mut blpapi_ProviderSession_t,
+        topics: *mut *const blpapi_Topic_t,
+        numTopics: usize,
+    ) -> c_int;
+    fn blpapi_ProviderSession_terminateSubscriptionsOnTopics(
+        session: *mut blpapi_ProviderSession_t,
+        topics: *mut *const blpapi_Topic_t,
+        numTopics: usize,
+        message: *const c_char,
+    ) -> c_int;
+    fn blpapi_ProviderSession_publish(
+        session: *mut blpapi_ProviderSession_t,
+        event: *mut blpapi_Event_t,
+    ) -> c_int;
+    fn blpapi_ProviderSession_sendResponse(
+        session: *mut blpapi_ProviderSession_t,
+        event: *mut blpapi_Event_t,
+        isPartialResponse: c_int,
+    ) -> c_int;
+    fn blpapi_ProviderSession_getAbstractSession(
+        session: *mut blpapi_ProviderSession_t,
+    ) -> *mut blpapi_AbstractSession_t;
+    fn blpapi_ProviderSession_flushPublishedEvents(
+        session: *mut blpapi_ProviderSession_t,
+        allFlushed: *mut c_int,
+        timeoutMsecs: c_int,
+    ) -> c_int;
+    fn blpapi_ServiceRegistrationOptions_create() -> *mut blpapi_ServiceRegistrationOptions_t;
+    fn blpapi_ServiceRegistrationOptions_duplicate(
+        parameters: *const blpapi_ServiceRegistrationOptions_t,
+    ) -> *mut blpapi_ServiceRegistrationOptions_t;
+    fn blpapi_ServiceRegistrationOptions_destroy(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+    );
+    fn blpapi_ServiceRegistrationOptions_copy(
+        lhs: *mut blpapi_ServiceRegistrationOptions_t,
+        rhs: *const blpapi_ServiceRegistrationOptions_t,
+    );
+    fn blpapi_ServiceRegistrationOptions_addActiveSubServiceCodeRange(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+        start: c_int,
+        end: c_int,
+        priority: c_int,
+    ) -> c_int;
+    fn blpapi_ServiceRegistrationOptions_removeAllActiveSubServiceCodeRanges(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+    );
+    fn blpapi_ServiceRegistrationOptions_setGroupId(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+        groupId: *const c_char,
+        groupIdLength: c_uint,
+    );
+    fn blpapi_ServiceRegistrationOptions_setServicePriority(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+        priority: c_int,
+    ) -> c_int;
+    fn blpapi_ServiceRegistrationOptions_setPartsToRegister(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+        parts: c_int,
+    );
+    fn blpapi_ServiceRegistrationOptions_getGroupId(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+        groupdIdBuffer: *mut c_char,
+        groupIdLength: *mut c_int,
+    ) -> c_int;
+    fn blpapi_ServiceRegistrationOptions_getServicePriority(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+    ) -> c_int;
+    fn blpapi_ServiceRegistrationOptions_getPartsToRegister(
+        parameters: *mut blpapi_ServiceRegistrationOptions_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_create(event: *mut blpapi_Event_t) -> *mut blpapi_EventFormatter_t;
+    fn blpapi_EventFormatter_destroy(victim: *mut blpapi_EventFormatter_t);
+    fn blpapi_EventFormatter_appendMessage(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *mut blpapi_Name_t,
+        topic: *const blpapi_Topic_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendMessageSeq(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *mut blpapi_Name_t,
+        topic: *const blpapi_Topic_t,
+        sequenceNumber: c_uint,
+        arg1: c_uint,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendResponse(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *mut blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendRecapMessage(
+        formatter: *mut blpapi_EventFormatter_t,
+        topic: *const blpapi_Topic_t,
+        cid: *const blpapi_CorrelationId_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendRecapMessageSeq(
+        formatter: *mut blpapi_EventFormatter_t,
+        topic: *const blpapi_Topic_t,
+        cid: *const blpapi_CorrelationId_t,
+        sequenceNumber: c_uint,
+        arg1: c_uint,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendFragmentedRecapMessage(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *mut blpapi_Name_t,
+        topic: *const blpapi_Topic_t,
+        cid: *const blpapi_CorrelationId_t,
+        fragmentType: c_int,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendFragmentedRecapMessageSeq(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *mut blpapi_Name_t,
+        topic: *const blpapi_Topic_t,
+        fragmentType: c_int,
+        sequenceNumber: c_uint,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueBool(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: blpapi_Bool_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueChar(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: c_char,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueInt32(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: blpapi_Int32_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueInt64(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: blpapi_Int64_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueFloat32(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: blpapi_Float32_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueFloat64(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: blpapi_Float64_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueDatetime(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: *const blpapi_Datetime_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueHighPrecisionDatetime(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: *const blpapi_HighPrecisionDatetime_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueString(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: *const c_char,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueFromName(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+        value: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_setValueNull(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_pushElement(
+        formatter: *mut blpapi_EventFormatter_t,
+        typeString: *const c_char,
+        typeName: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_popElement(formatter: *mut blpapi_EventFormatter_t) -> c_int;
+    fn blpapi_EventFormatter_appendValueBool(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: blpapi_Bool_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueChar(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: c_char,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueInt32(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: blpapi_Int32_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueInt64(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: blpapi_Int64_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueFloat32(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: blpapi_Float32_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueFloat64(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: blpapi_Float64_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueDatetime(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: *const blpapi_Datetime_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueHighPrecisionDatetime(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: *const blpapi_HighPrecisionDatetime_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueString(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: *const c_char,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendValueFromName(
+        formatter: *mut blpapi_EventFormatter_t,
+        value: *const blpapi_Name_t,
+    ) -> c_int;
+    fn blpapi_EventFormatter_appendElement(formatter: *mut blpapi_EventFormatter_t) -> c_int;
+    fn blpapi_TestUtil_createEvent(event: *mut *mut blpapi_Event_t, eventType: c_int) -> c_int;
+    fn blpapi_TestUtil_deserializeService(
+        schema: *const c_char,
+        schemaLength: usize,
+        service: *mut *mut blpapi_Service_t,
+    ) -> c_int;
+    fn blpapi_TestUtil_serializeService(
+        streamWriter: blpapi_StreamWriter_t,
+        stream: *mut c_void,
+        service: *const blpapi_Service_t,
+    ) -> c_int;
+    fn blpapi_DiagnosticsUtil_memoryInfo(buffer: *mut c_char, bufferLength: usize) -> c_int;
+    fn blpapi_HighResolutionClock_now(timePoint: *mut blpapi_TimePoint_t) -> c_int;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_library() {
+        let res: Result<Api, Error> =
+            unsafe { Api::load(OsStr::new("/nonexistent/libblpapi3_64.so")) };
+
+        assert!(matches!(res, Err(Error::Library { .. })));
+    }
+}
@@ -1,5 +1,9 @@
 use crate::bindings::*;
-use crate::{datetime::Datetime, name::Name, Error};
+use crate::{
+    datetime::{Datetime, HighPrecisionDatetime},
+    name::Name,
+    Error,
+};
 use std::{
     ffi::{CStr, CString},
     marker::PhantomData,
@@ -45,6 +49,36 @@ impl Element {
         unsafe { blpapi_Element_hasElement(self.ptr, name, named.0) != 0 }
     }
 
+    pub fn datatype(&self) -> DataType {
+        unsafe { blpapi_Element_datatype(self.ptr).into() }
+    }
+
+    pub fn is_array(&self) -> bool {
+        unsafe { blpapi_Element_isArray(self.ptr) != 0 }
+    }
+
+    pub fn is_complex_type(&self) -> bool {
+        unsafe { blpapi_Element_isComplexType(self.ptr) != 0 }
+    }
+
+    pub fn is_null(&self) -> bool {
+        unsafe { blpapi_Element_isNull(self.ptr) != 0 }
+    }
+
+    pub fn is_null_value(&self, index: usize) -> bool {
+        unsafe { blpapi_Element_isNullValue(self.ptr, index) != 0 }
+    }
+
+    pub fn get_choice(&self) -> Option<Element> {
+        unsafe {
+            let mut element: *mut blpapi_Element = ptr::null_mut();
+
+            let res: i32 = blpapi_Element_getChoice(self.ptr, &mut element as *mut _);
+
+            Element::opt(res, element)
+        }
+    }
+
     pub fn num_values(&self) -> usize {
         unsafe { blpapi_Element_numValues(self.ptr) }
     }
@@ -104,6 +138,25 @@ impl Element {
         }
     }
 
+    pub fn set_choice(&mut self, name: &str) -> Result<Element, Error> {
+        unsafe {
+            let mut ptr: *mut blpapi_Element = ptr::null_mut();
+            let name: CString = CString::new(name).unwrap();
+
+            let res: i32 = blpapi_Element_setChoice(
+                self.ptr,
+                &mut ptr as *mut _,
+                name.as_ptr(),
+                ptr::null(),
+                0,
+            );
+
+            Error::check(res)?;
+
+            Ok(Element { ptr })
+        }
+    }
+
     pub fn append<V: SetValue>(&mut self, value: V) -> Result<(), Error> {
         value.append_to(self)
     }
@@ -150,10 +203,62 @@ impl Element {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataType {
+    Bool,
+    Char,
+    Byte,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    String,
+    ByteArray,
+    Date,
+    Time,
+    Decimal,
+    Datetime,
+    Enumeration,
+    Sequence,
+    Choice,
+    CorrelationId,
+    Unknown = -1,
+}
+
+#[allow(non_upper_case_globals)]
+impl From<c_int> for DataType {
+    fn from(v: c_int) -> Self {
+        match v {
+            blpapi_DataType_t_BLPAPI_DATATYPE_BOOL => DataType::Bool,
+            blpapi_DataType_t_BLPAPI_DATATYPE_CHAR => DataType::Char,
+            blpapi_DataType_t_BLPAPI_DATATYPE_BYTE => DataType::Byte,
+            blpapi_DataType_t_BLPAPI_DATATYPE_INT32 => DataType::Int32,
+            blpapi_DataType_t_BLPAPI_DATATYPE_INT64 => DataType::Int64,
+            blpapi_DataType_t_BLPAPI_DATATYPE_FLOAT32 => DataType::Float32,
+            blpapi_DataType_t_BLPAPI_DATATYPE_FLOAT64 => DataType::Float64,
+            blpapi_DataType_t_BLPAPI_DATATYPE_STRING => DataType::String,
+            blpapi_DataType_t_BLPAPI_DATATYPE_BYTEARRAY => DataType::ByteArray,
+            blpapi_DataType_t_BLPAPI_DATATYPE_DATE => DataType::Date,
+            blpapi_DataType_t_BLPAPI_DATATYPE_TIME => DataType::Time,
+            blpapi_DataType_t_BLPAPI_DATATYPE_DECIMAL => DataType::Decimal,
+            blpapi_DataType_t_BLPAPI_DATATYPE_DATETIME => DataType::Datetime,
+            blpapi_DataType_t_BLPAPI_DATATYPE_ENUMERATION => DataType::Enumeration,
+            blpapi_DataType_t_BLPAPI_DATATYPE_SEQUENCE => DataType::Sequence,
+            blpapi_DataType_t_BLPAPI_DATATYPE_CHOICE => DataType::Choice,
+            blpapi_DataType_t_BLPAPI_DATATYPE_CORRELATION_ID => DataType::CorrelationId,
+            _ => DataType::Unknown,
+        }
+    }
+}
+
 pub trait GetValue: Sized {
     fn get_at(element: &Element, index: usize) -> Option<Self>;
 }
 
+pub trait ToElement {
+    fn to_element(&self, element: &mut Element) -> Result<(), Error>;
+}
+
 pub trait SetValue: Sized {
     fn set_at(self, element: &mut Element, index: usize) -> Result<(), Error>;
 
@@ -397,6 +502,53 @@ impl GetValue for Datetime {
     }
 }
 
+impl GetValue for HighPrecisionDatetime {
+    fn get_at(element: &Element, index: usize) -> Option<Self> {
+        unsafe {
+            let mut tmp: HighPrecisionDatetime = HighPrecisionDatetime::default();
+
+            let res: i32 =
+                blpapi_Element_getValueAsHighPrecisionDatetime(element.ptr, &mut tmp.0, index);
+
+            if res == 0 {
+                Some(tmp)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl GetValue for chrono::NaiveDate {
+    fn get_at(element: &Element, index: usize) -> Option<Self> {
+        Datetime::get_at(element, index)?.try_into().ok()
+    }
+}
+
+impl GetValue for chrono::NaiveTime {
+    fn get_at(element: &Element, index: usize) -> Option<Self> {
+        HighPrecisionDatetime::get_at(element, index)?
+            .try_into()
+            .ok()
+    }
+}
+
+impl GetValue for chrono::NaiveDateTime {
+    fn get_at(element: &Element, index: usize) -> Option<Self> {
+        HighPrecisionDatetime::get_at(element, index)?
+            .try_into()
+            .ok()
+    }
+}
+
+impl GetValue for chrono::DateTime<chrono::FixedOffset> {
+    fn get_at(element: &Element, index: usize) -> Option<Self> {
+        HighPrecisionDatetime::get_at(element, index)?
+            .try_into()
+            .ok()
+    }
+}
+
 impl<T: GetValue> GetValue for Option<T> {
     fn get_at(element: &Element, index: usize) -> Option<Self> {
         T::get_at(element, index).map(Some)
@@ -471,6 +623,52 @@ impl<'a> SetValue for &'a Datetime {
     }
 }
 
+impl SetValue for &HighPrecisionDatetime {
+    fn set_at(self, element: &mut Element, index: usize) -> Result<(), Error> {
+        unsafe {
+            let res: i32 = blpapi_Element_setValueHighPrecisionDatetime(
+                element.ptr,
+                &self.0 as *const _,
+                index,
+            );
+
+            Error::check(res)
+        }
+    }
+
+    fn set(self, element: &mut Element, name: &str) -> Result<(), Error> {
+        unsafe {
+            let named_element: *const blpapi_Name = ptr::null();
+
+            let name: CString = CString::new(name).unwrap();
+
+            let res: i32 = blpapi_Element_setElementHighPrecisionDatetime(
+                element.ptr,
+                name.as_ptr(),
+                named_element,
+                &self.0 as *const _,
+            );
+
+            Error::check(res)
+        }
+    }
+
+    fn set_named(self, element: &mut Element, named_element: &Name) -> Result<(), Error> {
+        unsafe {
+            let name: *const i8 = ptr::null();
+
+            let res: i32 = blpapi_Element_setElementHighPrecisionDatetime(
+                element.ptr,
+                name,
+                named_element.0,
+                &self.0 as *const _,
+            );
+
+            Error::check(res)
+        }
+    }
+}
+
 pub struct Values<'a, V> {
     element: &'a Element,
     i: usize,
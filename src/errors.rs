@@ -1,4 +1,12 @@
+use crate::bindings::*;
 use crate::element::Element;
+use crate::version::Version;
+use std::ffi::CStr;
+use std::os::raw::c_int;
+
+const ERROR_CLASS_MASK: u32 = 0x00ff_0000;
+
+pub type Source = Box<dyn std::error::Error + Send + Sync>;
 
 #[derive(Debug)]
 pub enum Error {
@@ -11,6 +19,11 @@ pub enum Error {
     ExpiredAuthToken,
     TokenInUse,
     Generic(i32),
+    Api {
+        code: i32,
+        class: ErrorClass,
+        description: String,
+    },
     NotFound(String),
     Security {
         security: String,
@@ -18,18 +31,150 @@ pub enum Error {
         sub_category: Option<String>,
         message: String,
     },
+    Field {
+        security: String,
+        field: String,
+        category: String,
+        sub_category: Option<String>,
+        message: String,
+    },
     TimeOut,
+    SessionStartupFailure,
+    SessionTerminated,
+    ServiceOpenFailure(String),
+    RequestFailure(String),
+    TokenGenerationFailure(String),
+    AuthorizationFailure(String),
+    Deserialize(Source),
+    Conversion(String),
+    Cache(Source),
+    Library {
+        path: String,
+        source: Source,
+    },
+    VersionMismatch {
+        bindings: Version,
+        library: Version,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    Unknown,
+    InvalidState,
+    InvalidArgument,
+    Io,
+    Conversion,
+    OutOfBounds,
+    NotFound,
+    FieldNotFound,
+    Unsupported,
+}
+
+impl ErrorClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorClass::Unknown => "unknown error",
+            ErrorClass::InvalidState => "invalid state",
+            ErrorClass::InvalidArgument => "illegal argument",
+            ErrorClass::Io => "i/o error",
+            ErrorClass::Conversion => "invalid conversion",
+            ErrorClass::OutOfBounds => "index out of range",
+            ErrorClass::NotFound => "not found",
+            ErrorClass::FieldNotFound => "field not found",
+            ErrorClass::Unsupported => "unsupported operation",
+        }
+    }
+}
+
+impl From<c_int> for ErrorClass {
+    fn from(code: c_int) -> Self {
+        match code as u32 & ERROR_CLASS_MASK {
+            BLPAPI_INVALIDSTATE_CLASS => ErrorClass::InvalidState,
+            BLPAPI_INVALIDARG_CLASS => ErrorClass::InvalidArgument,
+            BLPAPI_IOERROR_CLASS => ErrorClass::Io,
+            BLPAPI_CNVERROR_CLASS => ErrorClass::Conversion,
+            BLPAPI_BOUNDSERROR_CLASS => ErrorClass::OutOfBounds,
+            BLPAPI_NOTFOUND_CLASS => ErrorClass::NotFound,
+            BLPAPI_FLDNOTFOUND_CLASS => ErrorClass::FieldNotFound,
+            BLPAPI_UNSUPPORTED_CLASS => ErrorClass::Unsupported,
+            _ => ErrorClass::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "{:?}", self)
+        match self {
+            Error::InternalError => write!(f, "internal error"),
+            Error::InvalidUser => write!(f, "invalid user"),
+            Error::NotLoggedIn => write!(f, "user is not logged in"),
+            Error::InvalidDisplay => write!(f, "invalid display"),
+            Error::EntitlementRefresh => write!(f, "entitlements are being refreshed"),
+            Error::InvalidAuthToken => write!(f, "invalid authorization token"),
+            Error::ExpiredAuthToken => write!(f, "authorization token has expired"),
+            Error::TokenInUse => write!(f, "authorization token is already in use"),
+            Error::Generic(code) => write!(f, "blpapi error {:#x}", code),
+            Error::Api {
+                code,
+                class,
+                description,
+            } if description.is_empty() => write!(f, "{} ({:#x})", class, code),
+            Error::Api {
+                code,
+                class,
+                description,
+            } => write!(f, "{}: {} ({:#x})", class, description, code),
+            Error::NotFound(name) => write!(f, "'{}' not found", name),
+            Error::Security {
+                security,
+                category,
+                message,
+                ..
+            } => write!(f, "{}: {} {}", security, category, message),
+            Error::Field {
+                security,
+                field,
+                category,
+                message,
+                ..
+            } => write!(f, "{} {}: {} {}", security, field, category, message),
+            Error::TimeOut => write!(f, "timed out"),
+            Error::SessionStartupFailure => write!(f, "session failed to start"),
+            Error::SessionTerminated => write!(f, "session terminated"),
+            Error::ServiceOpenFailure(service) => write!(f, "failed to open {}", service),
+            Error::RequestFailure(reason) => write!(f, "request failed: {}", reason),
+            Error::TokenGenerationFailure(reason) => {
+                write!(f, "token generation failed: {}", reason)
+            }
+            Error::AuthorizationFailure(reason) => write!(f, "authorization failed: {}", reason),
+            Error::Deserialize(source) => write!(f, "deserialization failed: {}", source),
+            Error::Conversion(message) => write!(f, "conversion failed: {}", message),
+            Error::Cache(source) => write!(f, "cache error: {}", source),
+            Error::Library { path, source } => write!(f, "cannot load {}: {}", path, source),
+            Error::VersionMismatch { bindings, library } => write!(
+                f,
+                "blpapi {} is not compatible with bindings for {}",
+                library, bindings
+            ),
+        }
     }
 }
 
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match self {
+            Error::Deserialize(source) | Error::Cache(source) | Error::Library { source, .. } => {
+                Some(source.as_ref())
+            }
+            _ => None,
+        }
     }
 }
 
@@ -47,14 +192,67 @@ impl Error {
                 106 => Err(Error::InvalidAuthToken),
                 107 => Err(Error::ExpiredAuthToken),
                 108 => Err(Error::TokenInUse),
-                _ => {
-                    log::debug!("Unrecognized error code: {}...", res);
-                    Err(Error::Generic(res))
-                }
+                _ => Err(Error::from_code(res)),
+            }
+        }
+    }
+
+    pub fn from_code(code: i32) -> Error {
+        let description: String = unsafe {
+            let ptr: *const i8 = blpapi_getLastErrorDescription(code);
+
+            if ptr.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            }
+        };
+
+        log::debug!("Error code {:#x}: {}...", code, description);
+
+        Error::Api {
+            code,
+            class: ErrorClass::from(code),
+            description,
+        }
+    }
+
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            Error::Generic(code) | Error::Api { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    pub fn class(&self) -> Option<ErrorClass> {
+        match self {
+            Error::Api { class, .. } => Some(*class),
+            Error::Generic(code) => Some(ErrorClass::from(*code)),
+            _ => None,
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::TimeOut
+            | Error::SessionStartupFailure
+            | Error::SessionTerminated
+            | Error::ServiceOpenFailure(_)
+            | Error::EntitlementRefresh => true,
+            Error::Api { code, class, .. } => {
+                *class == ErrorClass::Io || *code as u32 == BLPAPI_ERROR_INVALID_SESSION
+            }
+            Error::Security { category, .. } | Error::Field { category, .. } => {
+                category == "TIMEOUT"
             }
+            _ => false,
         }
     }
 
+    pub fn is_permanent(&self) -> bool {
+        !self.is_retryable()
+    }
+
     pub(crate) fn security(security: String, element: Element) -> Error {
         let category: String = element
             .get_element("category")
@@ -76,4 +274,86 @@ impl Error {
             message,
         }
     }
+
+    pub(crate) fn field(security: String, element: Element) -> Error {
+        let field: String = element
+            .get_element("fieldId")
+            .and_then(|e: Element| e.get_at(0))
+            .unwrap_or_default();
+
+        match element.get_element("errorInfo") {
+            Some(info) => match Error::security(security, info) {
+                Error::Security {
+                    security,
+                    category,
+                    sub_category,
+                    message,
+                } => Error::Field {
+                    security,
+                    field,
+                    category,
+                    sub_category,
+                    message,
+                },
+                error => error,
+            },
+            None => Error::Field {
+                security,
+                field,
+                category: String::new(),
+                sub_category: None,
+                message: String::new(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_classes() {
+        assert_eq!(
+            ErrorClass::from(BLPAPI_ERROR_ILLEGAL_ARG as c_int),
+            ErrorClass::InvalidArgument
+        );
+        assert_eq!(
+            ErrorClass::from(BLPAPI_ERROR_ITEM_NOT_FOUND as c_int),
+            ErrorClass::NotFound
+        );
+        assert_eq!(
+            ErrorClass::from(BLPAPI_ERROR_UNSUPPORTED_OPERATION as c_int),
+            ErrorClass::Unsupported
+        );
+        assert_eq!(
+            ErrorClass::from(BLPAPI_ERROR_CONNECT_FAILED as c_int),
+            ErrorClass::Io
+        );
+    }
+
+    #[test]
+    fn retryable() {
+        let error: Error = Error::Api {
+            code: BLPAPI_ERROR_CONNECT_FAILED as i32,
+            class: ErrorClass::Io,
+            description: "Connection refused".to_owned(),
+        };
+
+        assert!(error.is_retryable());
+        assert_eq!(error.to_string(), "i/o error: Connection refused (0x30008)");
+
+        assert!(Error::TimeOut.is_retryable());
+        assert!(Error::InvalidUser.is_permanent());
+        assert!(Error::NotFound("PX_LAST".to_owned()).is_permanent());
+    }
+
+    #[test]
+    fn sources() {
+        let error: Error = Error::Cache(Box::new(std::io::Error::other("disk full")));
+
+        assert_eq!(error.to_string(), "cache error: disk full");
+        assert!(std::error::Error::source(&error).is_some());
+        assert!(std::error::Error::source(&Error::TimeOut).is_none());
+    }
 }
@@ -4,6 +4,9 @@ use std::os::raw::c_int;
 
 pub struct Event(pub(crate) *mut blpapi_Event_t);
 
+unsafe impl Send for Event {}
+unsafe impl Sync for Event {}
+
 impl Event {
     pub fn event_type(&self) -> EventType {
         unsafe { blpapi_Event_eventType(self.0).into() }
@@ -14,6 +17,20 @@ impl Event {
     }
 }
 
+impl Clone for Event {
+    fn clone(&self) -> Self {
+        unsafe { blpapi_Event_addRef(self.0) };
+
+        Event(self.0)
+    }
+}
+
+impl Drop for Event {
+    fn drop(&mut self) {
+        unsafe { blpapi_Event_release(self.0) };
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
     Admin,
@@ -0,0 +1,479 @@
+use crate::{
+    batch::Batch,
+    datetime::Datetime,
+    element::{DataType, Element},
+    event::Event,
+    name,
+    overrides::Overrides,
+    service::Service,
+    session::{self, HistOptions, SessionSync},
+    Error,
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use polars::prelude::{Column, DataFrame, NamedFrom, Series};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Date(NaiveDate),
+    Datetime(NaiveDateTime),
+    Time(NaiveTime),
+}
+
+impl Value {
+    pub fn from_element(element: &Element) -> Value {
+        if element.is_array() || element.is_complex_type() {
+            log::debug!(
+                "Skipping non scalar field {} in frame...",
+                element.string_name()
+            );
+
+            return Value::Null;
+        }
+
+        if element.is_null() {
+            return Value::Null;
+        }
+
+        let value: Option<Value> = match element.datatype() {
+            DataType::Bool => element.value().map(Value::Bool),
+            DataType::Char | DataType::Byte | DataType::Int32 => {
+                element.value::<i32>().map(|v| Value::Int(v as i64))
+            }
+            DataType::Int64 => element.value().map(Value::Int),
+            DataType::Float32 | DataType::Float64 | DataType::Decimal => {
+                element.value().map(Value::Float)
+            }
+            DataType::String | DataType::ByteArray | DataType::Enumeration => {
+                element.value().map(Value::String)
+            }
+            DataType::Date | DataType::Time | DataType::Datetime => {
+                element.value::<Datetime>().and_then(Value::from_datetime)
+            }
+            _ => None,
+        };
+
+        value.unwrap_or(Value::Null)
+    }
+
+    fn from_datetime(datetime: Datetime) -> Option<Value> {
+        match (datetime.has_date(), datetime.has_time()) {
+            (true, true) => datetime.try_into().ok().map(Value::Datetime),
+            (true, false) => datetime.try_into().ok().map(Value::Date),
+            (false, true) => datetime.try_into().ok().map(Value::Time),
+            (false, false) => None,
+        }
+    }
+
+    fn kind(&self) -> Option<Kind> {
+        match self {
+            Value::Null => None,
+            Value::Bool(_) => Some(Kind::Bool),
+            Value::Int(_) => Some(Kind::Int),
+            Value::Float(_) => Some(Kind::Float),
+            Value::String(_) => Some(Kind::String),
+            Value::Date(_) => Some(Kind::Date),
+            Value::Datetime(_) => Some(Kind::Datetime),
+            Value::Time(_) => Some(Kind::Time),
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Value::Null => Ok(()),
+            Value::Bool(v) => write!(f, "{}", v),
+            Value::Int(v) => write!(f, "{}", v),
+            Value::Float(v) => write!(f, "{}", v),
+            Value::String(v) => write!(f, "{}", v),
+            Value::Date(v) => write!(f, "{}", v),
+            Value::Datetime(v) => write!(f, "{}", v),
+            Value::Time(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Bool,
+    Int,
+    Float,
+    String,
+    Date,
+    Datetime,
+    Time,
+}
+
+pub(crate) struct FrameBuilder<K> {
+    keys: Vec<K>,
+    rows: HashMap<K, usize>,
+    fields: Vec<String>,
+    columns: Vec<Vec<Value>>,
+}
+
+impl<K: Clone + Eq + Hash> FrameBuilder<K> {
+    pub(crate) fn new(fields: &[&str]) -> Self {
+        FrameBuilder {
+            keys: Vec::new(),
+            rows: HashMap::new(),
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+            columns: vec![Vec::new(); fields.len()],
+        }
+    }
+
+    fn row(&mut self, key: K) -> usize {
+        if let Some(row) = self.rows.get(&key) {
+            return *row;
+        }
+
+        let row: usize = self.keys.len();
+
+        self.keys.push(key.clone());
+        self.rows.insert(key, row);
+
+        for column in self.columns.iter_mut() {
+            column.push(Value::Null);
+        }
+
+        row
+    }
+
+    pub(crate) fn insert(&mut self, key: K, field: &str, value: Value) {
+        let row: usize = self.row(key);
+
+        if let Some(index) = self
+            .fields
+            .iter()
+            .position(|f| f.eq_ignore_ascii_case(field))
+        {
+            self.columns[index][row] = value;
+        }
+    }
+
+    fn on_fields(&mut self, key: K, fields: &Element) {
+        self.row(key.clone());
+
+        for field in fields.elements() {
+            self.insert(
+                key.clone(),
+                &field.string_name(),
+                Value::from_element(&field),
+            );
+        }
+    }
+
+    fn into_columns(self, keys: Vec<Column>) -> Result<DataFrame, Error> {
+        let mut columns: Vec<Column> = keys;
+
+        for (field, values) in self.fields.iter().zip(self.columns) {
+            columns.push(series(field, values).into());
+        }
+
+        DataFrame::new(columns).map_err(|e| Error::Conversion(e.to_string()))
+    }
+}
+
+impl FrameBuilder<String> {
+    pub(crate) fn finish(self) -> Result<DataFrame, Error> {
+        let securities: Series = Series::new("security".into(), &self.keys);
+
+        self.into_columns(vec![securities.into()])
+    }
+}
+
+impl FrameBuilder<(String, NaiveDate)> {
+    pub(crate) fn finish(mut self) -> Result<DataFrame, Error> {
+        let mut order: Vec<usize> = (0..self.keys.len()).collect();
+
+        order.sort_by(|a, b| self.keys[*a].cmp(&self.keys[*b]));
+
+        for column in self.columns.iter_mut() {
+            *column = order
+                .iter()
+                .map(|row| std::mem::replace(&mut column[*row], Value::Null))
+                .collect();
+        }
+
+        let (securities, dates): (Vec<String>, Vec<NaiveDate>) =
+            order.iter().map(|row| self.keys[*row].clone()).unzip();
+
+        self.into_columns(vec![
+            Series::new("security".into(), securities).into(),
+            Series::new("date".into(), dates).into(),
+        ])
+    }
+}
+
+fn series(name: &str, values: Vec<Value>) -> Series {
+    let mut kinds = values.iter().filter_map(Value::kind);
+
+    let kind: Option<Kind> = match kinds.next() {
+        Some(first) => kinds.try_fold(first, |kind, other| match (kind, other) {
+            (k, o) if k == o => Some(k),
+            (Kind::Int, Kind::Float) | (Kind::Float, Kind::Int) => Some(Kind::Float),
+            _ => None,
+        }),
+        None => Some(Kind::String),
+    };
+
+    let name = name.into();
+
+    match kind {
+        Some(Kind::Bool) => Series::new(
+            name,
+            collect(values, |v| match v {
+                Value::Bool(v) => Some(v),
+                _ => None,
+            }),
+        ),
+        Some(Kind::Int) => Series::new(
+            name,
+            collect(values, |v| match v {
+                Value::Int(v) => Some(v),
+                _ => None,
+            }),
+        ),
+        Some(Kind::Float) => Series::new(
+            name,
+            collect(values, |v| match v {
+                Value::Int(v) => Some(v as f64),
+                Value::Float(v) => Some(v),
+                _ => None,
+            }),
+        ),
+        Some(Kind::Date) => Series::new(
+            name,
+            collect(values, |v| match v {
+                Value::Date(v) => Some(v),
+                _ => None,
+            }),
+        ),
+        Some(Kind::Datetime) => Series::new(
+            name,
+            collect(values, |v| match v {
+                Value::Datetime(v) => Some(v),
+                _ => None,
+            }),
+        ),
+        Some(Kind::Time) => Series::new(
+            name,
+            collect(values, |v| match v {
+                Value::Time(v) => Some(v),
+                _ => None,
+            }),
+        ),
+        _ => Series::new(
+            name,
+            collect(values, |v| match v {
+                Value::Null => None,
+                Value::String(v) => Some(v),
+                v => Some(v.to_string()),
+            }),
+        ),
+    }
+}
+
+fn collect<T, F: Fn(Value) -> Option<T>>(values: Vec<Value>, f: F) -> Vec<Option<T>> {
+    values.into_iter().map(f).collect()
+}
+
+pub(crate) fn on_ref_data(
+    event: &Event,
+    frame: &mut Batch<FrameBuilder<String>>,
+) -> Result<(), Error> {
+    for message in event.messages().map(|m| m.element()) {
+        if let Some(securities) = message.get_named_element(&name::SECURITY_DATA) {
+            for security in securities.values::<Element>() {
+                let ticker: String = match session::on_security(&security, &mut frame.errors) {
+                    Some(ticker) => ticker,
+                    None => continue,
+                };
+
+                if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+                    frame.data.on_fields(ticker, &fields);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn on_hist_data(
+    event: &Event,
+    frame: &mut Batch<FrameBuilder<(String, NaiveDate)>>,
+) -> Result<(), Error> {
+    for message in event.messages().map(|m| m.element()) {
+        let security: Element = match message.get_named_element(&name::SECURITY_DATA) {
+            Some(security) => security,
+            None => continue,
+        };
+
+        let ticker: String = match session::on_security(&security, &mut frame.errors) {
+            Some(ticker) => ticker,
+            None => continue,
+        };
+
+        if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+            for points in fields.values::<Element>() {
+                let date: NaiveDate = match points.element_value::<NaiveDate>("date") {
+                    Some(date) => date,
+                    None => {
+                        log::warn!("Missing date in historical data...");
+
+                        continue;
+                    }
+                };
+
+                frame.data.on_fields((ticker.clone(), date), &points);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn finish<K, F>(frame: Batch<FrameBuilder<K>>, f: F) -> Result<Batch<DataFrame>, Error>
+where
+    F: FnOnce(FrameBuilder<K>) -> Result<DataFrame, Error>,
+{
+    Ok(Batch {
+        data: f(frame.data)?,
+        errors: frame.errors,
+    })
+}
+
+impl SessionSync {
+    pub fn ref_data_frame<I>(
+        &mut self,
+        securities: I,
+        fields: &[&str],
+    ) -> Result<Batch<DataFrame>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.ref_data_frame_with_overrides(securities, fields, &Overrides::default())
+    }
+
+    pub fn ref_data_frame_with_overrides<I>(
+        &mut self,
+        securities: I,
+        fields: &[&str],
+        overrides: &Overrides,
+    ) -> Result<Batch<DataFrame>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = session::collect_securities(securities);
+        let mut frame: Batch<FrameBuilder<String>> = Batch::new(FrameBuilder::new(fields));
+
+        for request in session::ref_data_requests(&service, &securities, fields, overrides) {
+            for event in self.send(request?, None)? {
+                on_ref_data(&event?, &mut frame)?;
+            }
+        }
+
+        finish(frame, FrameBuilder::<String>::finish)
+    }
+
+    pub fn hist_data_frame<I>(
+        &mut self,
+        securities: I,
+        fields: &[&str],
+        options: HistOptions,
+    ) -> Result<Batch<DataFrame>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = session::collect_securities(securities);
+        let mut frame: Batch<FrameBuilder<(String, NaiveDate)>> =
+            Batch::new(FrameBuilder::new(fields));
+
+        for request in session::hist_data_requests(
+            &service,
+            &securities,
+            fields,
+            &options,
+            options.overrides(),
+        ) {
+            for event in self.send(request?, None)? {
+                on_hist_data(&event?, &mut frame)?;
+            }
+        }
+
+        finish(frame, FrameBuilder::<(String, NaiveDate)>::finish)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polars::prelude::DataType as PolarsType;
+
+    #[test]
+    fn wide_frame() -> Result<(), Error> {
+        let mut frame: FrameBuilder<String> = FrameBuilder::new(&["PX_LAST", "NAME", "VOLUME"]);
+
+        frame.insert("IBM US Equity".to_owned(), "PX_LAST", Value::Int(140));
+        frame.insert(
+            "IBM US Equity".to_owned(),
+            "NAME",
+            Value::String("IBM".to_owned()),
+        );
+        frame.insert("AAPL US Equity".to_owned(), "PX_LAST", Value::Float(180.5));
+        frame.insert("AAPL US Equity".to_owned(), "VOLUME", Value::Int(1_000));
+
+        let frame: DataFrame = frame.finish()?;
+
+        assert_eq!(frame.shape(), (2, 4));
+        assert_eq!(
+            frame.column("PX_LAST").unwrap().dtype(),
+            &PolarsType::Float64
+        );
+        assert_eq!(frame.column("NAME").unwrap().dtype(), &PolarsType::String);
+        assert_eq!(frame.column("VOLUME").unwrap().dtype(), &PolarsType::Int64);
+        assert_eq!(frame.column("VOLUME").unwrap().null_count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_frame() -> Result<(), Error> {
+        let mut frame: FrameBuilder<(String, NaiveDate)> = FrameBuilder::new(&["PX_LAST"]);
+
+        for (security, day, price) in [("B", 2, 2.), ("A", 2, 1.5), ("A", 1, 1.)] {
+            let date: NaiveDate = NaiveDate::from_ymd_opt(2020, 1, day).unwrap();
+
+            frame.insert((security.to_owned(), date), "PX_LAST", Value::Float(price));
+        }
+
+        let frame: DataFrame = frame.finish()?;
+
+        assert_eq!(frame.get_column_names(), &["security", "date", "PX_LAST"]);
+        assert_eq!(frame.column("date").unwrap().dtype(), &PolarsType::Date);
+
+        let prices: Vec<Option<f64>> = frame
+            .column("PX_LAST")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect();
+
+        assert_eq!(prices, vec![Some(1.), Some(1.5), Some(2.)]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+use crate::bindings::*;
+use crate::{
+    correlation_id::CorrelationId,
+    element::Element,
+    event::{Event, EventType},
+    name,
+    service::Service,
+    Error,
+};
+use std::os::raw::c_int;
+use std::ptr;
+
+pub struct Identity(pub(crate) *mut blpapi_Identity_t);
+
+unsafe impl Send for Identity {}
+unsafe impl Sync for Identity {}
+
+impl Identity {
+    pub fn is_authorized(&self, service: &Service) -> bool {
+        unsafe { blpapi_Identity_isAuthorized(self.0, service.0) != 0 }
+    }
+
+    pub fn has_entitlements(&self, service: &Service, entitlement_ids: &[i32]) -> bool {
+        self.failed_entitlements(service, entitlement_ids)
+            .is_empty()
+    }
+
+    pub fn failed_entitlements(&self, service: &Service, entitlement_ids: &[i32]) -> Vec<i32> {
+        let mut failed: Vec<c_int> = vec![0; entitlement_ids.len()];
+        let mut failed_count: c_int = failed.len() as c_int;
+
+        let res: c_int = unsafe {
+            blpapi_Identity_hasEntitlements(
+                self.0,
+                service.0,
+                ptr::null(),
+                entitlement_ids.as_ptr(),
+                entitlement_ids.len(),
+                failed.as_mut_ptr(),
+                &mut failed_count,
+            )
+        };
+
+        if res != 0 {
+            return Vec::new();
+        }
+
+        failed.truncate(failed_count.max(0) as usize);
+
+        if failed.is_empty() {
+            failed = entitlement_ids.to_vec();
+        }
+
+        failed
+    }
+
+    pub fn has_element_entitlements(&self, service: &Service, eids: &Element) -> bool {
+        unsafe {
+            blpapi_Identity_hasEntitlements(
+                self.0,
+                service.0,
+                eids.ptr,
+                ptr::null(),
+                0,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            ) != 0
+        }
+    }
+
+    pub fn seat_type(&self) -> SeatType {
+        let mut seat_type: c_int = BLPAPI_SEATTYPE_INVALID_SEAT;
+
+        unsafe { blpapi_Identity_getSeatType(self.0, &mut seat_type) };
+
+        seat_type.into()
+    }
+}
+
+impl Clone for Identity {
+    fn clone(&self) -> Self {
+        unsafe { blpapi_Identity_addRef(self.0) };
+
+        Identity(self.0)
+    }
+}
+
+impl Drop for Identity {
+    fn drop(&mut self) {
+        unsafe { blpapi_Identity_release(self.0) }
+    }
+}
+
+impl std::fmt::Debug for Identity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(f, "Identity: '{:?}'...", self.seat_type())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeatType {
+    Bps,
+    NonBps,
+    Invalid,
+}
+
+impl From<c_int> for SeatType {
+    fn from(v: c_int) -> Self {
+        match v as u32 {
+            BLPAPI_SEATTYPE_BPS => SeatType::Bps,
+            BLPAPI_SEATTYPE_NONBPS => SeatType::NonBps,
+            _ => SeatType::Invalid,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserAuthentication {
+    OsLogon,
+    DirectoryService(String),
+}
+
+impl std::fmt::Display for UserAuthentication {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            UserAuthentication::OsLogon => write!(f, "AuthenticationType=OS_LOGON"),
+            UserAuthentication::DirectoryService(property) => write!(
+                f,
+                "AuthenticationType=DIRECTORY_SERVICE;DirSvcPropertyName={}",
+                property
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Authentication {
+    User(UserAuthentication),
+    Application(String),
+    UserAndApplication(UserAuthentication, String),
+}
+
+impl std::fmt::Display for Authentication {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Authentication::User(user) => write!(f, "{}", user),
+            Authentication::Application(name) => write!(
+                f,
+                "AuthenticationMode=APPLICATION_ONLY;ApplicationAuthenticationType=APPNAME_AND_KEY;ApplicationName={}",
+                name
+            ),
+            Authentication::UserAndApplication(user, name) => write!(
+                f,
+                "AuthenticationMode=USER_AND_APPLICATION;{};ApplicationAuthenticationType=APPNAME_AND_KEY;ApplicationName={}",
+                user, name
+            ),
+        }
+    }
+}
+
+pub(crate) fn on_token(
+    event: &Event,
+    correlation_id: &CorrelationId,
+) -> Option<Result<String, Error>> {
+    if event.event_type() != EventType::TokenStatus {
+        return None;
+    }
+
+    for message in event
+        .messages()
+        .filter(|m| m.has_correlation_id(correlation_id))
+    {
+        let message_type = message.message_type();
+
+        if message_type == *name::TOKEN_SUCCESS {
+            return Some(
+                message
+                    .element()
+                    .element_value::<String>("token")
+                    .ok_or_else(|| Error::NotFound("token".to_owned())),
+            );
+        } else if message_type == *name::TOKEN_FAILURE {
+            return Some(Err(Error::TokenGenerationFailure(message.failure_reason())));
+        }
+    }
+
+    None
+}
+
+pub(crate) fn on_authorization(
+    event: &Event,
+    correlation_id: &CorrelationId,
+) -> Option<Result<(), Error>> {
+    match event.event_type() {
+        EventType::Response
+        | EventType::PartialResponse
+        | EventType::RequestStatus
+        | EventType::AuthorizationStatus => (),
+        _ => return None,
+    }
+
+    for message in event
+        .messages()
+        .filter(|m| m.has_correlation_id(correlation_id))
+    {
+        let message_type = message.message_type();
+
+        if message_type == *name::AUTHORIZATION_SUCCESS {
+            return Some(Ok(()));
+        } else if message_type == *name::AUTHORIZATION_FAILURE
+            || message_type == *name::REQUEST_FAILURE
+        {
+            return Some(Err(Error::AuthorizationFailure(message.failure_reason())));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authentication_options() {
+        assert_eq!(
+            Authentication::User(UserAuthentication::OsLogon).to_string(),
+            "AuthenticationType=OS_LOGON"
+        );
+
+        assert_eq!(
+            Authentication::UserAndApplication(
+                UserAuthentication::DirectoryService("mail".to_owned()),
+                "app".to_owned()
+            )
+            .to_string(),
+            "AuthenticationMode=USER_AND_APPLICATION;AuthenticationType=DIRECTORY_SERVICE;DirSvcPropertyName=mail;ApplicationAuthenticationType=APPNAME_AND_KEY;ApplicationName=app"
+        );
+    }
+}
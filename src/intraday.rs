@@ -0,0 +1,604 @@
+use crate::{
+    datetime::Datetime, element::Element, event::Event, name, request::Request, service::Service,
+    Error,
+};
+use chrono::{Duration, NaiveDateTime};
+
+const MAX_BARS_PER_REQUEST: i64 = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarEventType {
+    Trade,
+    Bid,
+    Ask,
+    BestBid,
+    BestAsk,
+}
+
+impl BarEventType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BarEventType::Trade => "TRADE",
+            BarEventType::Bid => "BID",
+            BarEventType::Ask => "ASK",
+            BarEventType::BestBid => "BEST_BID",
+            BarEventType::BestAsk => "BEST_ASK",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BarOptions {
+    event_type: BarEventType,
+    interval: u32,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    gap_fill_initial_bar: Option<bool>,
+    adjustment_normal: Option<bool>,
+    adjustment_abnormal: Option<bool>,
+    adjustment_split: Option<bool>,
+    adjustment_follow_dpdf: Option<bool>,
+    chunk: Option<Duration>,
+}
+
+impl BarOptions {
+    pub fn new(start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        BarOptions {
+            event_type: BarEventType::Trade,
+            interval: 1,
+            start,
+            end,
+            gap_fill_initial_bar: None,
+            adjustment_normal: None,
+            adjustment_abnormal: None,
+            adjustment_split: None,
+            adjustment_follow_dpdf: None,
+            chunk: None,
+        }
+    }
+
+    pub fn with_event_type(mut self, event_type: BarEventType) -> Self {
+        self.event_type = event_type;
+        self
+    }
+
+    pub fn with_interval(mut self, minutes: u32) -> Self {
+        self.interval = minutes.clamp(1, 1440);
+        self
+    }
+
+    pub fn with_gap_fill_initial_bar(mut self, gap_fill_initial_bar: bool) -> Self {
+        self.gap_fill_initial_bar = Some(gap_fill_initial_bar);
+        self
+    }
+
+    pub fn with_adjustment_normal(mut self, adjustment_normal: bool) -> Self {
+        self.adjustment_normal = Some(adjustment_normal);
+        self
+    }
+
+    pub fn with_adjustment_abnormal(mut self, adjustment_abnormal: bool) -> Self {
+        self.adjustment_abnormal = Some(adjustment_abnormal);
+        self
+    }
+
+    pub fn with_adjustment_split(mut self, adjustment_split: bool) -> Self {
+        self.adjustment_split = Some(adjustment_split);
+        self
+    }
+
+    pub fn with_adjustment_follow_dpdf(mut self, adjustment_follow_dpdf: bool) -> Self {
+        self.adjustment_follow_dpdf = Some(adjustment_follow_dpdf);
+        self
+    }
+
+    pub fn with_chunk(mut self, chunk: Duration) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    pub fn chunks(&self) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        let chunk: Duration = self
+            .chunk
+            .unwrap_or_else(|| Duration::minutes(self.interval as i64 * MAX_BARS_PER_REQUEST))
+            .max(Duration::minutes(self.interval as i64));
+
+        split(self.start, self.end, chunk)
+    }
+
+    fn apply(
+        &self,
+        request: &mut Request,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<(), Error> {
+        let mut element: Element = request.element();
+
+        element.set("eventType", self.event_type.as_str())?;
+        element.set("interval", self.interval as i32)?;
+        element.set("startDateTime", &Datetime::from(start))?;
+        element.set("endDateTime", &Datetime::from(end))?;
+
+        if let Some(gap_fill_initial_bar) = self.gap_fill_initial_bar {
+            element.set("gapFillInitialBar", gap_fill_initial_bar)?;
+        }
+
+        if let Some(adjustment_normal) = self.adjustment_normal {
+            element.set("adjustmentNormal", adjustment_normal)?;
+        }
+
+        if let Some(adjustment_abnormal) = self.adjustment_abnormal {
+            element.set("adjustmentAbnormal", adjustment_abnormal)?;
+        }
+
+        if let Some(adjustment_split) = self.adjustment_split {
+            element.set("adjustmentSplit", adjustment_split)?;
+        }
+
+        if let Some(adjustment_follow_dpdf) = self.adjustment_follow_dpdf {
+            element.set("adjustmentFollowDPDF", adjustment_follow_dpdf)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn split(
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    chunk: Duration,
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let mut chunks: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::new();
+    let mut from: NaiveDateTime = start;
+
+    while from < end {
+        let to: NaiveDateTime = (from + chunk).min(end);
+
+        // Request bounds are inclusive, stop short of the next chunk so boundary ticks come once.
+        if to < end {
+            chunks.push((from, to - Duration::milliseconds(1)));
+        } else {
+            chunks.push((from, to));
+        }
+
+        from = to;
+    }
+
+    chunks
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    pub time: NaiveDateTime,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: i64,
+    pub num_events: i64,
+    pub value: f64,
+}
+
+impl Bar {
+    fn from_element(element: &Element) -> Option<Self> {
+        Some(Bar {
+            time: element.element_value("time")?,
+            open: element.element_value("open")?,
+            high: element.element_value("high")?,
+            low: element.element_value("low")?,
+            close: element.element_value("close")?,
+            volume: element.element_value("volume").unwrap_or_default(),
+            num_events: element.element_value("numEvents").unwrap_or_default(),
+            value: element.element_value("value").unwrap_or_default(),
+        })
+    }
+}
+
+pub(crate) fn bar_data_requests<'a>(
+    service: &'a Service,
+    security: &'a str,
+    options: &'a BarOptions,
+) -> impl Iterator<Item = Result<Request, Error>> + 'a {
+    options.chunks().into_iter().map(move |(start, end)| {
+        let mut request: Request = service.create_request("IntradayBarRequest")?;
+
+        request
+            .element()
+            .set_named(&name::SECURITY_NAME, security)?;
+
+        options.apply(&mut request, start, end)?;
+
+        Ok(request)
+    })
+}
+
+pub(crate) fn on_bar_data(event: &Event, security: &str, bars: &mut Vec<Bar>) -> Result<(), Error> {
+    for message in event.messages().map(|m| m.element()) {
+        if let Some(error) = message.get_named_element(&name::RESPONSE_ERROR) {
+            return Err(Error::security(security.to_owned(), error));
+        }
+
+        if let Some(data) = message
+            .get_named_element(&name::BAR_DATA)
+            .and_then(|e| e.get_named_element(&name::BAR_TICK_DATA))
+        {
+            let mut skipped: usize = 0;
+
+            for element in data.values::<Element>() {
+                match Bar::from_element(&element) {
+                    Some(bar) => bars.push(bar),
+                    None => skipped += 1,
+                }
+            }
+
+            if skipped > 0 {
+                log::warn!("Skipping {} incomplete bars for {}...", skipped, security);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn sort_bars(bars: &mut Vec<Bar>) {
+    bars.sort_by_key(|b| b.time);
+    bars.dedup_by_key(|b| b.time);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TickEventType {
+    Trade,
+    Bid,
+    Ask,
+    BidBest,
+    AskBest,
+    BidYield,
+    AskYield,
+    MidPrice,
+    AtTrade,
+    BestBid,
+    BestAsk,
+    Settle,
+    Other(String),
+}
+
+impl TickEventType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TickEventType::Trade => "TRADE",
+            TickEventType::Bid => "BID",
+            TickEventType::Ask => "ASK",
+            TickEventType::BidBest => "BID_BEST",
+            TickEventType::AskBest => "ASK_BEST",
+            TickEventType::BidYield => "BID_YIELD",
+            TickEventType::AskYield => "ASK_YIELD",
+            TickEventType::MidPrice => "MID_PRICE",
+            TickEventType::AtTrade => "AT_TRADE",
+            TickEventType::BestBid => "BEST_BID",
+            TickEventType::BestAsk => "BEST_ASK",
+            TickEventType::Settle => "SETTLE",
+            TickEventType::Other(s) => s,
+        }
+    }
+}
+
+impl std::str::FromStr for TickEventType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "TRADE" => Ok(TickEventType::Trade),
+            "BID" => Ok(TickEventType::Bid),
+            "ASK" => Ok(TickEventType::Ask),
+            "BID_BEST" => Ok(TickEventType::BidBest),
+            "ASK_BEST" => Ok(TickEventType::AskBest),
+            "BID_YIELD" => Ok(TickEventType::BidYield),
+            "ASK_YIELD" => Ok(TickEventType::AskYield),
+            "MID_PRICE" => Ok(TickEventType::MidPrice),
+            "AT_TRADE" => Ok(TickEventType::AtTrade),
+            "BEST_BID" => Ok(TickEventType::BestBid),
+            "BEST_ASK" => Ok(TickEventType::BestAsk),
+            "SETTLE" => Ok(TickEventType::Settle),
+            _ => Err(Error::NotFound(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TickOptions {
+    event_types: Vec<TickEventType>,
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    include_condition_codes: Option<bool>,
+    include_exchange_codes: Option<bool>,
+    include_broker_codes: Option<bool>,
+    include_rps_codes: Option<bool>,
+    include_bic_mic_codes: Option<bool>,
+    include_non_plottable_events: Option<bool>,
+    include_action_codes: Option<bool>,
+    include_indicator_codes: Option<bool>,
+    include_trade_time: Option<bool>,
+    chunk: Duration,
+}
+
+impl TickOptions {
+    pub fn new(start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        TickOptions {
+            event_types: vec![TickEventType::Trade],
+            start,
+            end,
+            include_condition_codes: None,
+            include_exchange_codes: None,
+            include_broker_codes: None,
+            include_rps_codes: None,
+            include_bic_mic_codes: None,
+            include_non_plottable_events: None,
+            include_action_codes: None,
+            include_indicator_codes: None,
+            include_trade_time: None,
+            chunk: Duration::days(1),
+        }
+    }
+
+    pub fn with_event_types<I: IntoIterator<Item = TickEventType>>(
+        mut self,
+        event_types: I,
+    ) -> Self {
+        self.event_types = event_types.into_iter().collect();
+        self
+    }
+
+    pub fn with_condition_codes(mut self, include_condition_codes: bool) -> Self {
+        self.include_condition_codes = Some(include_condition_codes);
+        self
+    }
+
+    pub fn with_exchange_codes(mut self, include_exchange_codes: bool) -> Self {
+        self.include_exchange_codes = Some(include_exchange_codes);
+        self
+    }
+
+    pub fn with_broker_codes(mut self, include_broker_codes: bool) -> Self {
+        self.include_broker_codes = Some(include_broker_codes);
+        self
+    }
+
+    pub fn with_rps_codes(mut self, include_rps_codes: bool) -> Self {
+        self.include_rps_codes = Some(include_rps_codes);
+        self
+    }
+
+    pub fn with_bic_mic_codes(mut self, include_bic_mic_codes: bool) -> Self {
+        self.include_bic_mic_codes = Some(include_bic_mic_codes);
+        self
+    }
+
+    pub fn with_non_plottable_events(mut self, include_non_plottable_events: bool) -> Self {
+        self.include_non_plottable_events = Some(include_non_plottable_events);
+        self
+    }
+
+    pub fn with_action_codes(mut self, include_action_codes: bool) -> Self {
+        self.include_action_codes = Some(include_action_codes);
+        self
+    }
+
+    pub fn with_indicator_codes(mut self, include_indicator_codes: bool) -> Self {
+        self.include_indicator_codes = Some(include_indicator_codes);
+        self
+    }
+
+    pub fn with_trade_time(mut self, include_trade_time: bool) -> Self {
+        self.include_trade_time = Some(include_trade_time);
+        self
+    }
+
+    pub fn with_chunk(mut self, chunk: Duration) -> Self {
+        self.chunk = chunk;
+        self
+    }
+
+    pub fn chunks(&self) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        split(self.start, self.end, self.chunk.max(Duration::seconds(1)))
+    }
+
+    fn apply(
+        &self,
+        request: &mut Request,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<(), Error> {
+        let mut element: Element = request.element();
+
+        element.set("startDateTime", &Datetime::from(start))?;
+        element.set("endDateTime", &Datetime::from(end))?;
+
+        let flags: [(&str, Option<bool>); 9] = [
+            ("includeConditionCodes", self.include_condition_codes),
+            ("includeExchangeCodes", self.include_exchange_codes),
+            ("includeBrokerCodes", self.include_broker_codes),
+            ("includeRpsCodes", self.include_rps_codes),
+            ("includeBicMicCodes", self.include_bic_mic_codes),
+            (
+                "includeNonPlottableEvents",
+                self.include_non_plottable_events,
+            ),
+            ("includeActionCodes", self.include_action_codes),
+            ("includeIndicatorCodes", self.include_indicator_codes),
+            ("includeTradeTime", self.include_trade_time),
+        ];
+
+        for (name, flag) in flags {
+            if let Some(flag) = flag {
+                element.set(name, flag)?;
+            }
+        }
+
+        for event_type in self.event_types.iter() {
+            request.append("eventTypes", event_type.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tick {
+    pub time: Datetime,
+    pub event_type: TickEventType,
+    pub value: f64,
+    pub size: i64,
+    pub condition_codes: Option<String>,
+    pub exchange_code: Option<String>,
+    pub broker_buy_code: Option<String>,
+    pub broker_sell_code: Option<String>,
+    pub rps_code: Option<String>,
+    pub mic_code: Option<String>,
+}
+
+impl Tick {
+    fn from_element(element: &Element) -> Option<Self> {
+        Some(Tick {
+            time: element.element_value("time")?,
+            event_type: element
+                .element_value::<String>("type")
+                .map(|s| s.parse().unwrap_or(TickEventType::Other(s)))?,
+            value: element.element_value("value")?,
+            size: element.element_value("size").unwrap_or_default(),
+            condition_codes: optional_value(element, "conditionCodes"),
+            exchange_code: optional_value(element, "exchangeCode"),
+            broker_buy_code: optional_value(element, "brokerBuyCode"),
+            broker_sell_code: optional_value(element, "brokerSellCode"),
+            rps_code: optional_value(element, "rpsCode"),
+            mic_code: optional_value(element, "micCode"),
+        })
+    }
+}
+
+fn optional_value(element: &Element, name: &str) -> Option<String> {
+    if element.has_element(name) {
+        element.element_value(name)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn tick_data_requests<'a>(
+    service: &'a Service,
+    security: &'a str,
+    options: &'a TickOptions,
+) -> impl Iterator<Item = Result<Request, Error>> + 'a {
+    options.chunks().into_iter().map(move |(start, end)| {
+        let mut request: Request = service.create_request("IntradayTickRequest")?;
+
+        request
+            .element()
+            .set_named(&name::SECURITY_NAME, security)?;
+
+        options.apply(&mut request, start, end)?;
+
+        Ok(request)
+    })
+}
+
+pub(crate) fn on_tick_data(
+    event: &Event,
+    security: &str,
+    ticks: &mut Vec<Tick>,
+) -> Result<(), Error> {
+    for message in event.messages().map(|m| m.element()) {
+        if let Some(error) = message.get_named_element(&name::RESPONSE_ERROR) {
+            return Err(Error::security(security.to_owned(), error));
+        }
+
+        if let Some(data) = message
+            .get_named_element(&name::TICK_DATA)
+            .and_then(|e| e.get_named_element(&name::TICK_DATA))
+        {
+            let mut skipped: usize = 0;
+
+            for element in data.values::<Element>() {
+                match Tick::from_element(&element) {
+                    Some(tick) => ticks.push(tick),
+                    None => skipped += 1,
+                }
+            }
+
+            if skipped > 0 {
+                log::warn!("Skipping {} incomplete ticks for {}...", skipped, security);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn datetime(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 4, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn chunks() {
+        let options: BarOptions =
+            BarOptions::new(datetime(1, 0), datetime(3, 12)).with_chunk(Duration::days(1));
+
+        assert_eq!(
+            options.chunks(),
+            [
+                (datetime(1, 0), datetime(2, 0) - Duration::milliseconds(1)),
+                (datetime(2, 0), datetime(3, 0) - Duration::milliseconds(1)),
+                (datetime(3, 0), datetime(3, 12)),
+            ]
+        );
+    }
+
+    #[test]
+    fn default_chunk() {
+        let options: BarOptions = BarOptions::new(datetime(1, 0), datetime(30, 0));
+
+        assert_eq!(options.chunks().len(), 1);
+        assert!(BarOptions::new(datetime(2, 0), datetime(1, 0))
+            .chunks()
+            .is_empty());
+    }
+
+    #[test]
+    fn tick_chunks() {
+        let options: TickOptions = TickOptions::new(datetime(1, 0), datetime(3, 12))
+            .with_event_types([TickEventType::Bid, TickEventType::Ask]);
+
+        assert_eq!(options.chunks().len(), 3);
+        assert_eq!(
+            "BID_BEST".parse::<TickEventType>().unwrap(),
+            TickEventType::BidBest
+        );
+        assert_eq!(
+            TickEventType::Other("NEW_TYPE".to_owned()).as_str(),
+            "NEW_TYPE"
+        );
+    }
+
+    #[test]
+    fn chunk_boundaries() {
+        let boundary: NaiveDateTime = datetime(2, 0);
+        let chunks = TickOptions::new(datetime(1, 0), datetime(3, 0))
+            .with_chunk(Duration::days(1))
+            .chunks();
+
+        let containing: usize = chunks
+            .iter()
+            .filter(|(from, to)| *from <= boundary && boundary <= *to)
+            .count();
+
+        assert_eq!(containing, 1);
+        assert_eq!(chunks.last().map(|(_, to)| *to), Some(datetime(3, 0)));
+    }
+}
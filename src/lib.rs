@@ -1,18 +1,60 @@
+extern crate self as bloomberg;
+
+pub mod batch;
+#[cfg(not(feature = "dynamic"))]
+#[cfg_attr(feature = "bindgen", path = "generated.rs")]
+pub mod bindings;
+#[cfg(feature = "dynamic")]
+#[path = "dynamic.rs"]
 pub mod bindings;
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod correlation_id;
 pub mod datetime;
+#[cfg(feature = "serde")]
+pub mod de;
 pub mod element;
 pub mod errors;
 pub mod event;
+#[cfg(feature = "polars")]
+pub mod frame;
+pub mod identity;
+pub mod intraday;
+pub mod logging;
 pub mod message;
 pub mod message_iterator;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod name;
+pub mod overrides;
+#[cfg(feature = "arrow")]
+pub mod record_batch;
 pub mod ref_data;
 pub mod request;
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod ser;
 pub mod service;
 pub mod session;
+#[cfg(feature = "async")]
+pub mod session_async;
 pub mod session_options;
+pub mod subscription;
+pub mod time_serie;
+pub mod tls_options;
+pub mod version;
 
+pub use batch::Batch;
+pub use chrono;
 pub use errors::Error;
-pub use ref_data::RefData;
+pub use identity::Identity;
+pub use overrides::Overrides;
+pub use ref_data::{FromElement, RefData};
+#[cfg(feature = "serde")]
+pub use serde_json;
 pub use session::SessionSync;
+
+#[cfg(feature = "derive")]
+pub use bloomberg_derive::{FromElement, RefData};
+#[cfg(feature = "async")]
+pub use session_async::SessionAsync;
@@ -0,0 +1,142 @@
+use crate::bindings::*;
+use crate::{datetime::Datetime, schema::string, Error};
+use std::os::raw::{c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+
+pub const TARGET: &str = "blpapi";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Off,
+    Fatal,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Severity {
+    pub fn level(self) -> Option<log::Level> {
+        match self {
+            Severity::Off => None,
+            Severity::Fatal | Severity::Error => Some(log::Level::Error),
+            Severity::Warn => Some(log::Level::Warn),
+            Severity::Info => Some(log::Level::Info),
+            Severity::Debug => Some(log::Level::Debug),
+            Severity::Trace => Some(log::Level::Trace),
+        }
+    }
+
+    fn as_raw(self) -> blpapi_Logging_Severity_t {
+        match self {
+            Severity::Off => blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_OFF,
+            Severity::Fatal => blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_FATAL,
+            Severity::Error => blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_ERROR,
+            Severity::Warn => blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_WARN,
+            Severity::Info => blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_INFO,
+            Severity::Debug => blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_DEBUG,
+            Severity::Trace => blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_TRACE,
+        }
+    }
+}
+
+#[allow(non_upper_case_globals)]
+impl From<c_int> for Severity {
+    fn from(v: c_int) -> Self {
+        match v {
+            blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_FATAL => Severity::Fatal,
+            blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_ERROR => Severity::Error,
+            blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_WARN => Severity::Warn,
+            blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_INFO => Severity::Info,
+            blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_DEBUG => Severity::Debug,
+            blpapi_Logging_Severity_t_blpapi_Logging_SEVERITY_TRACE => Severity::Trace,
+            _ => Severity::Off,
+        }
+    }
+}
+
+impl From<log::LevelFilter> for Severity {
+    fn from(filter: log::LevelFilter) -> Self {
+        match filter {
+            log::LevelFilter::Off => Severity::Off,
+            log::LevelFilter::Error => Severity::Error,
+            log::LevelFilter::Warn => Severity::Warn,
+            log::LevelFilter::Info => Severity::Info,
+            log::LevelFilter::Debug => Severity::Debug,
+            log::LevelFilter::Trace => Severity::Trace,
+        }
+    }
+}
+
+pub fn register(threshold: Severity) -> Result<(), Error> {
+    let res: i32 = unsafe { blpapi_Logging_registerCallback(Some(on_log), threshold.as_raw()) };
+
+    Error::check(res)
+}
+
+pub fn register_with_max_level() -> Result<(), Error> {
+    register(log::max_level().into())
+}
+
+pub fn set_level(threshold: Severity) -> Result<(), Error> {
+    register(threshold)
+}
+
+pub fn log_test_message(severity: Severity) {
+    unsafe { blpapi_Logging_logTestMessage(severity.as_raw()) }
+}
+
+unsafe extern "C" fn on_log(
+    thread_id: blpapi_UInt64_t,
+    severity: c_int,
+    timestamp: blpapi_Datetime_t,
+    category: *const c_char,
+    message: *const c_char,
+) {
+    let res = panic::catch_unwind(AssertUnwindSafe(|| {
+        let level: log::Level = match Severity::from(severity).level() {
+            Some(level) => level,
+            None => return,
+        };
+
+        let category: String = string(category);
+        let message: String = string(message);
+
+        log::log!(
+            target: TARGET,
+            level,
+            "[{}] {:?} {}: {}",
+            thread_id,
+            Datetime(timestamp),
+            category,
+            message
+        );
+    }));
+
+    if res.is_err() {
+        log::error!("Panic while forwarding blpapi log message...");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_levels() {
+        assert_eq!(Severity::Fatal.level(), Some(log::Level::Error));
+        assert_eq!(Severity::Off.level(), None);
+
+        for severity in [
+            Severity::Error,
+            Severity::Warn,
+            Severity::Info,
+            Severity::Trace,
+        ] {
+            assert_eq!(Severity::from(severity.as_raw()), severity);
+        }
+
+        assert_eq!(Severity::from(log::LevelFilter::Debug), Severity::Debug);
+    }
+}
@@ -1,8 +1,14 @@
 use crate::bindings::*;
-use crate::{correlation_id::CorrelationId, element::Element, event::Event, name::Name};
+use crate::{
+    correlation_id::CorrelationId,
+    element::Element,
+    event::Event,
+    name::{self, Name},
+};
 use std::ffi::CStr;
 use std::marker::PhantomData;
 
+#[derive(Clone)]
 pub struct Message<'a> {
     pub(crate) ptr: *mut blpapi_Message_t,
     pub(crate) _phantom: PhantomData<&'a Event>,
@@ -39,7 +45,7 @@ impl<'a> Message<'a> {
     }
 
     pub fn correlation_id(&self, index: usize) -> Option<CorrelationId> {
-        if index > self.num_correlation_ids() {
+        if index >= self.num_correlation_ids() {
             None
         } else {
             unsafe {
@@ -50,6 +56,25 @@ impl<'a> Message<'a> {
         }
     }
 
+    pub(crate) fn has_correlation_id(&self, correlation_id: &CorrelationId) -> bool {
+        let id: Option<u64> = correlation_id.as_u64();
+
+        (0..self.num_correlation_ids())
+            .filter_map(|i| self.correlation_id(i))
+            .any(|c| id.is_some() && c.as_u64() == id)
+    }
+
+    pub(crate) fn failure_reason(&self) -> String {
+        self.element()
+            .get_named_element(&name::REASON)
+            .and_then(|reason: Element| {
+                reason
+                    .element_value::<String>("message")
+                    .or_else(|| reason.element_value::<String>("description"))
+            })
+            .unwrap_or_else(|| self.type_string())
+    }
+
     pub fn element(&self) -> Element {
         Element { ptr: self.elements }
     }
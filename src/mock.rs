@@ -0,0 +1,822 @@
+use crate::bindings::*;
+use crate::{
+    batch::Batch,
+    correlation_id::CorrelationId,
+    datetime::Datetime,
+    event::{Event, EventType},
+    intraday::{self, Bar, Tick},
+    ref_data::RefData,
+    service::Service,
+    session::{self, EventSource, Events, SubscriptionEvents, TimeSerie},
+    Error,
+};
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CString;
+use std::os::raw::c_int;
+use std::ptr;
+
+/// A minimal `//blp/refdata` schema covering reference and historical data requests for
+/// common price fields, pass your own to [`deserialize_service`] for other fields.
+pub const REFDATA_SCHEMA: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<ServiceDefinition name="blp.refdata" version="1.0.0.0">
+  <service name="//blp/refdata" version="1.0.0.0">
+    <operation name="ReferenceDataRequest" serviceId="104">
+      <request>ReferenceDataRequest</request>
+      <response>ReferenceDataResponse</response>
+    </operation>
+    <operation name="HistoricalDataRequest" serviceId="104">
+      <request>HistoricalDataRequest</request>
+      <response>HistoricalDataResponse</response>
+    </operation>
+  </service>
+  <schema>
+    <sequenceType name="ReferenceDataRequest">
+      <element name="securities" type="String" minOccurs="1" maxOccurs="unbounded" />
+      <element name="fields" type="String" minOccurs="1" maxOccurs="unbounded" />
+    </sequenceType>
+    <sequenceType name="HistoricalDataRequest">
+      <element name="securities" type="String" minOccurs="1" maxOccurs="unbounded" />
+      <element name="fields" type="String" minOccurs="1" maxOccurs="unbounded" />
+      <element name="startDate" type="String" minOccurs="1" maxOccurs="1" />
+      <element name="endDate" type="String" minOccurs="0" maxOccurs="1" />
+    </sequenceType>
+    <sequenceType name="ErrorInfo">
+      <element name="source" type="String" minOccurs="0" maxOccurs="1" />
+      <element name="code" type="Int32" minOccurs="0" maxOccurs="1" />
+      <element name="category" type="String" minOccurs="0" maxOccurs="1" />
+      <element name="message" type="String" minOccurs="0" maxOccurs="1" />
+      <element name="subcategory" type="String" minOccurs="0" maxOccurs="1" />
+    </sequenceType>
+    <sequenceType name="FieldException">
+      <element name="fieldId" type="String" minOccurs="1" maxOccurs="1" />
+      <element name="errorInfo" type="ErrorInfo" minOccurs="1" maxOccurs="1" />
+    </sequenceType>
+    <sequenceType name="FieldData">
+      <element name="date" type="Date" minOccurs="0" maxOccurs="1" />
+      <element name="NAME" type="String" minOccurs="0" maxOccurs="1" />
+      <element name="PX_OPEN" type="Float64" minOccurs="0" maxOccurs="1" />
+      <element name="PX_HIGH" type="Float64" minOccurs="0" maxOccurs="1" />
+      <element name="PX_LOW" type="Float64" minOccurs="0" maxOccurs="1" />
+      <element name="PX_LAST" type="Float64" minOccurs="0" maxOccurs="1" />
+      <element name="PX_VOLUME" type="Int64" minOccurs="0" maxOccurs="1" />
+    </sequenceType>
+    <sequenceType name="ReferenceSecurityData">
+      <element name="security" type="String" minOccurs="1" maxOccurs="1" />
+      <element name="sequenceNumber" type="Int32" minOccurs="0" maxOccurs="1" />
+      <element name="securityError" type="ErrorInfo" minOccurs="0" maxOccurs="1" />
+      <element name="fieldExceptions" type="FieldException" minOccurs="0" maxOccurs="unbounded" />
+      <element name="fieldData" type="FieldData" minOccurs="0" maxOccurs="1" />
+    </sequenceType>
+    <sequenceType name="HistoricalSecurityData">
+      <element name="security" type="String" minOccurs="1" maxOccurs="1" />
+      <element name="sequenceNumber" type="Int32" minOccurs="0" maxOccurs="1" />
+      <element name="securityError" type="ErrorInfo" minOccurs="0" maxOccurs="1" />
+      <element name="fieldExceptions" type="FieldException" minOccurs="0" maxOccurs="unbounded" />
+      <element name="fieldData" type="FieldData" minOccurs="0" maxOccurs="unbounded" />
+    </sequenceType>
+    <choiceType name="ReferenceDataResponse">
+      <element name="responseError" type="ErrorInfo" minOccurs="1" maxOccurs="1" />
+      <element name="securityData" type="ReferenceSecurityData" minOccurs="1" maxOccurs="unbounded" />
+    </choiceType>
+    <choiceType name="HistoricalDataResponse">
+      <element name="responseError" type="ErrorInfo" minOccurs="1" maxOccurs="1" />
+      <element name="securityData" type="HistoricalSecurityData" minOccurs="1" maxOccurs="1" />
+    </choiceType>
+  </schema>
+</ServiceDefinition>
+"#;
+
+pub fn deserialize_service(schema: &str) -> Result<Service, Error> {
+    let mut ptr: *mut blpapi_Service_t = ptr::null_mut();
+
+    let res: i32 = unsafe {
+        blpapi_TestUtil_deserializeService(
+            schema.as_ptr() as *const _,
+            schema.len(),
+            &mut ptr as *mut _,
+        )
+    };
+
+    Error::check(res)?;
+
+    Ok(Service(ptr))
+}
+
+#[derive(Debug, Clone)]
+pub enum MockValue {
+    Bool(bool),
+    Int32(i32),
+    Int64(i64),
+    Float64(f64),
+    String(String),
+    Datetime(Datetime),
+    Null,
+    Element(MockElement),
+    Array(Vec<MockValue>),
+}
+
+impl From<bool> for MockValue {
+    fn from(v: bool) -> Self {
+        MockValue::Bool(v)
+    }
+}
+
+impl From<i32> for MockValue {
+    fn from(v: i32) -> Self {
+        MockValue::Int32(v)
+    }
+}
+
+impl From<i64> for MockValue {
+    fn from(v: i64) -> Self {
+        MockValue::Int64(v)
+    }
+}
+
+impl From<f64> for MockValue {
+    fn from(v: f64) -> Self {
+        MockValue::Float64(v)
+    }
+}
+
+impl From<&str> for MockValue {
+    fn from(v: &str) -> Self {
+        MockValue::String(v.to_owned())
+    }
+}
+
+impl From<String> for MockValue {
+    fn from(v: String) -> Self {
+        MockValue::String(v)
+    }
+}
+
+impl From<Datetime> for MockValue {
+    fn from(v: Datetime) -> Self {
+        MockValue::Datetime(v)
+    }
+}
+
+impl From<chrono::NaiveDate> for MockValue {
+    fn from(v: chrono::NaiveDate) -> Self {
+        MockValue::Datetime(v.into())
+    }
+}
+
+impl From<chrono::NaiveDateTime> for MockValue {
+    fn from(v: chrono::NaiveDateTime) -> Self {
+        MockValue::Datetime(v.into())
+    }
+}
+
+impl From<MockElement> for MockValue {
+    fn from(v: MockElement) -> Self {
+        MockValue::Element(v)
+    }
+}
+
+impl<T: Into<MockValue>> From<Vec<T>> for MockValue {
+    fn from(v: Vec<T>) -> Self {
+        MockValue::Array(v.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MockElement(Vec<(String, MockValue)>);
+
+impl MockElement {
+    pub fn new() -> Self {
+        MockElement::default()
+    }
+
+    pub fn with<N: Into<String>, V: Into<MockValue>>(mut self, name: N, value: V) -> Self {
+        self.0.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &MockValue)> {
+        self.0.iter().map(|(n, v)| (&n[..], v))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MockMessage {
+    message_type: String,
+    element: MockElement,
+}
+
+impl MockMessage {
+    pub fn new<S: Into<String>>(message_type: S) -> Self {
+        MockMessage {
+            message_type: message_type.into(),
+            element: MockElement::new(),
+        }
+    }
+
+    pub fn with<N: Into<String>, V: Into<MockValue>>(mut self, name: N, value: V) -> Self {
+        self.element = self.element.with(name, value);
+        self
+    }
+
+    pub fn message_type(&self) -> &str {
+        &self.message_type
+    }
+
+    pub fn element(&self) -> &MockElement {
+        &self.element
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MockEvent {
+    event_type: EventType,
+    correlation_id: Option<CorrelationId>,
+    messages: Vec<MockMessage>,
+}
+
+impl MockEvent {
+    pub fn new(event_type: EventType) -> Self {
+        MockEvent {
+            event_type,
+            correlation_id: None,
+            messages: Vec::new(),
+        }
+    }
+
+    pub fn response() -> Self {
+        MockEvent::new(EventType::Response)
+    }
+
+    pub fn partial_response() -> Self {
+        MockEvent::new(EventType::PartialResponse)
+    }
+
+    pub fn subscription_data() -> Self {
+        MockEvent::new(EventType::SubscriptionData)
+    }
+
+    pub fn subscription_status() -> Self {
+        MockEvent::new(EventType::SubscriptionStatus)
+    }
+
+    pub fn session_status() -> Self {
+        MockEvent::new(EventType::SessionStatus)
+    }
+
+    pub fn with_message(mut self, message: MockMessage) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    pub fn with_correlation_id(mut self, correlation_id: CorrelationId) -> Self {
+        self.correlation_id = Some(correlation_id);
+        self
+    }
+
+    pub fn event_type(&self) -> EventType {
+        self.event_type
+    }
+
+    pub fn correlation_id(&self) -> Option<CorrelationId> {
+        self.correlation_id
+    }
+
+    pub fn messages(&self) -> &[MockMessage] {
+        &self.messages
+    }
+
+    /// Builds a schema-less event, messages accept any element.
+    pub fn build(&self) -> Result<Event, Error> {
+        let mut ptr: *mut blpapi_Event_t = ptr::null_mut();
+
+        let res: i32 =
+            unsafe { blpapi_TestUtil_createEvent(&mut ptr as *mut _, event_type(self.event_type)) };
+
+        Error::check(res)?;
+
+        self.format(Event(ptr), None)
+    }
+
+    /// Builds a response checked against the schema of `service`, tagged with the event's
+    /// correlation id.
+    pub fn build_for(&self, service: &Service) -> Result<Event, Error> {
+        if !matches!(
+            self.event_type,
+            EventType::Response | EventType::PartialResponse
+        ) {
+            return self.build();
+        }
+
+        let correlation_id: CorrelationId = self
+            .correlation_id
+            .unwrap_or_else(|| CorrelationId::new_u64(0));
+
+        let mut ptr: *mut blpapi_Event_t = ptr::null_mut();
+
+        let res: i32 = unsafe {
+            blpapi_Service_createResponseEvent(
+                service.0,
+                &correlation_id.0 as *const _,
+                &mut ptr as *mut _,
+            )
+        };
+
+        Error::check(res)?;
+
+        self.format(Event(ptr), Some(service))
+    }
+
+    fn format(&self, event: Event, service: Option<&Service>) -> Result<Event, Error> {
+        let formatter: EventFormatter = EventFormatter::new(&event);
+
+        for message in self.messages.iter() {
+            match self.event_type {
+                EventType::Response | EventType::PartialResponse => {
+                    let operation: String = match service {
+                        Some(service) => operation(service, &message.message_type),
+                        None => message.message_type.clone(),
+                    };
+
+                    formatter.append_response(&operation)?
+                }
+                _ => formatter.append_message(&message.message_type)?,
+            }
+
+            formatter.write_element(&message.element)?;
+        }
+
+        Ok(event)
+    }
+}
+
+fn operation(service: &Service, message_type: &str) -> String {
+    service
+        .operations()
+        .find(|o| {
+            o.response_definitions()
+                .any(|d| d.string_name() == message_type)
+        })
+        .map(|o| o.name())
+        .unwrap_or_else(|| message_type.to_owned())
+}
+
+fn event_type(event_type: EventType) -> c_int {
+    let event_type: u32 = match event_type {
+        EventType::Admin => BLPAPI_EVENTTYPE_ADMIN,
+        EventType::SessionStatus => BLPAPI_EVENTTYPE_SESSION_STATUS,
+        EventType::SubscriptionStatus => BLPAPI_EVENTTYPE_SUBSCRIPTION_STATUS,
+        EventType::RequestStatus => BLPAPI_EVENTTYPE_REQUEST_STATUS,
+        EventType::Response => BLPAPI_EVENTTYPE_RESPONSE,
+        EventType::PartialResponse => BLPAPI_EVENTTYPE_PARTIAL_RESPONSE,
+        EventType::SubscriptionData => BLPAPI_EVENTTYPE_SUBSCRIPTION_DATA,
+        EventType::ServiceStatus => BLPAPI_EVENTTYPE_SERVICE_STATUS,
+        EventType::Timeout => BLPAPI_EVENTTYPE_TIMEOUT,
+        EventType::AuthorizationStatus => BLPAPI_EVENTTYPE_AUTHORIZATION_STATUS,
+        EventType::ResolutionStatus => BLPAPI_EVENTTYPE_RESOLUTION_STATUS,
+        EventType::TopicStatus => BLPAPI_EVENTTYPE_TOPIC_STATUS,
+        EventType::TokenStatus => BLPAPI_EVENTTYPE_TOKEN_STATUS,
+        EventType::Request => BLPAPI_EVENTTYPE_REQUEST,
+        EventType::Unknown => return -1,
+    };
+
+    event_type as c_int
+}
+
+struct EventFormatter(*mut blpapi_EventFormatter_t);
+
+impl EventFormatter {
+    fn new(event: &Event) -> Self {
+        unsafe { EventFormatter(blpapi_EventFormatter_create(event.0)) }
+    }
+
+    fn append_response(&self, operation: &str) -> Result<(), Error> {
+        let operation: CString = CString::new(operation).unwrap();
+
+        let res: i32 = unsafe {
+            blpapi_EventFormatter_appendResponse(self.0, operation.as_ptr(), ptr::null_mut())
+        };
+
+        Error::check(res)
+    }
+
+    fn append_message(&self, message_type: &str) -> Result<(), Error> {
+        let message_type: CString = CString::new(message_type).unwrap();
+
+        let res: i32 = unsafe {
+            blpapi_EventFormatter_appendMessage(
+                self.0,
+                message_type.as_ptr(),
+                ptr::null_mut(),
+                ptr::null(),
+            )
+        };
+
+        Error::check(res)
+    }
+
+    fn write_element(&self, element: &MockElement) -> Result<(), Error> {
+        for (name, value) in element.iter() {
+            self.set_value(name, value)?;
+        }
+
+        Ok(())
+    }
+
+    fn set_value(&self, name: &str, value: &MockValue) -> Result<(), Error> {
+        let name: CString = CString::new(name).unwrap();
+        let name_ptr: *const i8 = name.as_ptr();
+        let null: *const blpapi_Name_t = ptr::null();
+
+        let res: i32 = unsafe {
+            match value {
+                MockValue::Bool(v) => {
+                    blpapi_EventFormatter_setValueBool(self.0, name_ptr, null, *v as i32)
+                }
+                MockValue::Int32(v) => {
+                    blpapi_EventFormatter_setValueInt32(self.0, name_ptr, null, *v)
+                }
+                MockValue::Int64(v) => {
+                    blpapi_EventFormatter_setValueInt64(self.0, name_ptr, null, *v)
+                }
+                MockValue::Float64(v) => {
+                    blpapi_EventFormatter_setValueFloat64(self.0, name_ptr, null, *v)
+                }
+                MockValue::String(v) => {
+                    let v: CString = CString::new(&v[..]).unwrap();
+
+                    blpapi_EventFormatter_setValueString(self.0, name_ptr, null, v.as_ptr())
+                }
+                MockValue::Datetime(v) => {
+                    blpapi_EventFormatter_setValueDatetime(self.0, name_ptr, null, &v.0 as *const _)
+                }
+                MockValue::Null => blpapi_EventFormatter_setValueNull(self.0, name_ptr, null),
+                MockValue::Element(element) => {
+                    Error::check(blpapi_EventFormatter_pushElement(self.0, name_ptr, null))?;
+
+                    self.write_element(element)?;
+
+                    blpapi_EventFormatter_popElement(self.0)
+                }
+                MockValue::Array(values) => {
+                    Error::check(blpapi_EventFormatter_pushElement(self.0, name_ptr, null))?;
+
+                    for value in values.iter() {
+                        self.append_value(value)?;
+                    }
+
+                    blpapi_EventFormatter_popElement(self.0)
+                }
+            }
+        };
+
+        Error::check(res)
+    }
+
+    fn append_value(&self, value: &MockValue) -> Result<(), Error> {
+        let res: i32 = unsafe {
+            match value {
+                MockValue::Bool(v) => blpapi_EventFormatter_appendValueBool(self.0, *v as i32),
+                MockValue::Int32(v) => blpapi_EventFormatter_appendValueInt32(self.0, *v),
+                MockValue::Int64(v) => blpapi_EventFormatter_appendValueInt64(self.0, *v),
+                MockValue::Float64(v) => blpapi_EventFormatter_appendValueFloat64(self.0, *v),
+                MockValue::String(v) => {
+                    let v: CString = CString::new(&v[..]).unwrap();
+
+                    blpapi_EventFormatter_appendValueString(self.0, v.as_ptr())
+                }
+                MockValue::Datetime(v) => {
+                    blpapi_EventFormatter_appendValueDatetime(self.0, &v.0 as *const _)
+                }
+                MockValue::Element(element) => {
+                    Error::check(blpapi_EventFormatter_appendElement(self.0))?;
+
+                    self.write_element(element)?;
+
+                    blpapi_EventFormatter_popElement(self.0)
+                }
+                MockValue::Null | MockValue::Array(_) => {
+                    return Err(Error::NotFound("array value".to_owned()))
+                }
+            }
+        };
+
+        Error::check(res)
+    }
+}
+
+impl Drop for EventFormatter {
+    fn drop(&mut self) {
+        unsafe { blpapi_EventFormatter_destroy(self.0) }
+    }
+}
+
+#[derive(Default)]
+pub struct MockSession {
+    events: VecDeque<Event>,
+    service: Option<Service>,
+    cancelled: Vec<CorrelationId>,
+}
+
+impl MockSession {
+    pub fn new() -> Self {
+        MockSession::default()
+    }
+
+    /// Builds responses pushed afterwards against `service`, see [`MockEvent::build_for`].
+    pub fn with_service(mut self, service: Service) -> Self {
+        self.service = Some(service);
+        self
+    }
+
+    pub fn push(&mut self, event: Event) {
+        self.events.push_back(event);
+    }
+
+    pub fn push_mock(&mut self, event: &MockEvent) -> Result<(), Error> {
+        let event: Event = match self.service.as_ref() {
+            Some(service) => event.build_for(service)?,
+            None => event.build()?,
+        };
+
+        self.push(event);
+
+        Ok(())
+    }
+
+    pub fn with(mut self, event: MockEvent) -> Result<Self, Error> {
+        self.push_mock(&event)?;
+
+        Ok(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn next_event(&mut self, _timeout_ms: Option<u32>) -> Result<Event, Error> {
+        self.events.pop_front().ok_or(Error::TimeOut)
+    }
+
+    pub fn events(&mut self) -> Events<'_> {
+        Events::new(self, None)
+    }
+
+    /// Events of the request identified by `correlation_id`, as `SessionSync` would yield them.
+    pub fn request_events(&mut self, correlation_id: CorrelationId) -> Events<'_> {
+        Events::new(self, Some(correlation_id))
+    }
+
+    pub fn cancelled(&self) -> &[CorrelationId] {
+        &self.cancelled
+    }
+
+    pub fn subscription_events(&mut self) -> SubscriptionEvents<'_> {
+        SubscriptionEvents::new(self, None)
+    }
+
+    pub fn ref_data<R: RefData>(&mut self) -> Result<Batch<HashMap<String, R>>, Error> {
+        let mut ref_data: Batch<HashMap<String, R>> = Batch::default();
+
+        while !self.is_empty() {
+            for event in self.events() {
+                session::on_ref_data(&event?, &mut ref_data)?;
+            }
+        }
+
+        Ok(ref_data)
+    }
+
+    pub fn hist_data<R: RefData>(&mut self) -> Result<Batch<HashMap<String, TimeSerie<R>>>, Error> {
+        let mut hist_data: Batch<HashMap<String, TimeSerie<R>>> = Batch::default();
+
+        while !self.is_empty() {
+            for event in self.events() {
+                session::on_hist_data(&event?, &mut hist_data)?;
+            }
+        }
+
+        Ok(hist_data)
+    }
+
+    pub fn bar_data(&mut self, security: &str) -> Result<Vec<Bar>, Error> {
+        let mut bars: Vec<Bar> = Vec::new();
+
+        while !self.is_empty() {
+            for event in self.events() {
+                intraday::on_bar_data(&event?, security, &mut bars)?;
+            }
+        }
+
+        intraday::sort_bars(&mut bars);
+
+        Ok(bars)
+    }
+
+    pub fn tick_data(&mut self, security: &str) -> Result<Vec<Tick>, Error> {
+        let mut ticks: Vec<Tick> = Vec::new();
+
+        while !self.is_empty() {
+            for event in self.events() {
+                intraday::on_tick_data(&event?, security, &mut ticks)?;
+            }
+        }
+
+        Ok(ticks)
+    }
+}
+
+impl EventSource for MockSession {
+    fn next_event(&mut self, timeout_ms: Option<u32>) -> Result<Event, Error> {
+        MockSession::next_event(self, timeout_ms)
+    }
+
+    fn cancel(&mut self, correlation_id: &CorrelationId) -> Result<(), Error> {
+        self.cancelled.push(*correlation_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[derive(Debug, Default)]
+    struct Security {
+        px_last: f64,
+    }
+
+    impl RefData for Security {
+        const FIELDS: &'static [&'static str] = &["PX_LAST"];
+
+        fn on_field(&mut self, field: &str, element: &crate::element::Element) {
+            if field == "PX_LAST" {
+                if let Some(px_last) = element.get_at(0) {
+                    self.px_last = px_last;
+                }
+            }
+        }
+    }
+
+    fn security_data(security: &str, px_last: f64) -> MockElement {
+        MockElement::new()
+            .with("security", security)
+            .with("fieldData", MockElement::new().with("PX_LAST", px_last))
+    }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn mock_ref_data() -> Result<(), Error> {
+        let mut session: MockSession = MockSession::new()
+            .with(
+                MockEvent::partial_response().with_message(
+                    MockMessage::new("ReferenceDataResponse")
+                        .with("securityData", vec![security_data("IBM US Equity", 140.5)]),
+                ),
+            )?
+            .with(MockEvent::response().with_message(
+                MockMessage::new("ReferenceDataResponse").with(
+                    "securityData",
+                    vec![security_data("AAPL US Equity", 180.25)],
+                ),
+            ))?;
+
+        let ref_data: Batch<HashMap<String, Security>> = session.ref_data()?;
+
+        assert!(ref_data.is_complete());
+        assert_eq!(ref_data["IBM US Equity"].px_last, 140.5);
+        assert_eq!(ref_data["AAPL US Equity"].px_last, 180.25);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn mock_correlation_ids() -> Result<(), Error> {
+        let ours: CorrelationId = CorrelationId::new_u64(1);
+        let other: CorrelationId = CorrelationId::new_u64(2);
+
+        let response = |correlation_id: CorrelationId, security: &str, px_last: f64| {
+            MockEvent::response()
+                .with_correlation_id(correlation_id)
+                .with_message(
+                    MockMessage::new("ReferenceDataResponse")
+                        .with("securityData", vec![security_data(security, px_last)]),
+                )
+        };
+
+        let mut session: MockSession = MockSession::new()
+            .with_service(deserialize_service(REFDATA_SCHEMA)?)
+            .with(response(other, "AAPL US Equity", 180.25))?
+            .with(response(ours, "IBM US Equity", 140.5))?;
+
+        let mut ref_data: Batch<HashMap<String, Security>> = Batch::default();
+
+        for event in session.request_events(ours) {
+            session::on_ref_data(&event?, &mut ref_data)?;
+        }
+
+        assert_eq!(ref_data["IBM US Equity"].px_last, 140.5);
+        assert!(!ref_data.contains_key("AAPL US Equity"));
+        assert!(session.cancelled().is_empty());
+
+        session.push_mock(&response(other, "MSFT US Equity", 410.0))?;
+
+        drop(session.request_events(other));
+
+        assert_eq!(session.cancelled().len(), 1);
+        assert_eq!(session.cancelled()[0].as_u64(), Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn mock_request_failure() -> Result<(), Error> {
+        let mut session: MockSession = MockSession::new().with(
+            MockEvent::new(EventType::RequestStatus).with_message(
+                MockMessage::new("RequestFailure")
+                    .with("reason", MockElement::new().with("message", "Timed out")),
+            ),
+        )?;
+
+        let res: Option<Result<Event, Error>> = session.events().next();
+
+        assert!(matches!(res, Some(Err(Error::RequestFailure(reason))) if reason == "Timed out"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn mock_bulk_field() -> Result<(), Error> {
+        #[derive(Debug, Default, crate::FromElement)]
+        struct DividendRow {
+            #[bbg(field = "Ex-Date")]
+            ex_date: Option<NaiveDate>,
+            dividend_amount: f64,
+        }
+
+        #[derive(Debug, Default, crate::RefData)]
+        struct Dividends {
+            dvd_hist_all: Vec<DividendRow>,
+        }
+
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2020, 2, 7).unwrap();
+
+        let mut session: MockSession = MockSession::new().with(
+            MockEvent::response().with_message(MockMessage::new("ReferenceDataResponse").with(
+                "securityData",
+                vec![MockElement::new().with("security", "IBM US Equity").with(
+                    "fieldData",
+                    MockElement::new().with(
+                        "DVD_HIST_ALL",
+                        vec![MockElement::new()
+                                .with("Ex-Date", date)
+                                .with("Dividend Amount", 1.62)],
+                    ),
+                )],
+            )),
+        )?;
+
+        let ref_data: Batch<HashMap<String, Dividends>> = session.ref_data()?;
+        let dividends: &[DividendRow] = &ref_data["IBM US Equity"].dvd_hist_all;
+
+        assert_eq!(dividends.len(), 1);
+        assert_eq!(dividends[0].ex_date, Some(date));
+        assert_eq!(dividends[0].dividend_amount, 1.62);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn mock_hist_data() -> Result<(), Error> {
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+
+        let mut session: MockSession = MockSession::new().with(
+            MockEvent::response().with_message(MockMessage::new("HistoricalDataResponse").with(
+                "securityData",
+                MockElement::new().with("security", "IBM US Equity").with(
+                    "fieldData",
+                    vec![MockElement::new()
+                            .with("date", date)
+                            .with("PX_LAST", 135.42)],
+                ),
+            )),
+        )?;
+
+        let hist_data: Batch<HashMap<String, TimeSerie<Security>>> = session.hist_data()?;
+
+        assert_eq!(hist_data["IBM US Equity"].dates(), &[date]);
+        assert_eq!(hist_data["IBM US Equity"].values()[0].px_last, 135.42);
+
+        Ok(())
+    }
+}
@@ -6,10 +6,27 @@ lazy_static::lazy_static! {
     pub static ref SECURITY_NAME: Name = Name::new("security");
     pub static ref FIELD_DATA: Name = Name::new("fieldData");
     pub static ref SECURITY_ERROR: Name = Name::new("securityError");
+    pub static ref FIELD_EXCEPTIONS: Name = Name::new("fieldExceptions");
     pub static ref SECURITIES: Name = Name::new("securities");
     pub static ref FIELDS_NAME: Name = Name::new("fields");
-    pub static ref SESSION_TERMINATED: Name = Name::new("SesssionTerminated");
+    pub static ref SESSION_STARTED: Name = Name::new("SessionStarted");
+    pub static ref SESSION_TERMINATED: Name = Name::new("SessionTerminated");
     pub static ref SESSION_STARTUP_FAILURE: Name = Name::new("SessionStartupFailure");
+    pub static ref SERVICE_OPENED: Name = Name::new("ServiceOpened");
+    pub static ref SERVICE_OPEN_FAILURE: Name = Name::new("ServiceOpenFailure");
+    pub static ref SUBSCRIPTION_STARTED: Name = Name::new("SubscriptionStarted");
+    pub static ref SUBSCRIPTION_FAILURE: Name = Name::new("SubscriptionFailure");
+    pub static ref SUBSCRIPTION_TERMINATED: Name = Name::new("SubscriptionTerminated");
+    pub static ref REASON: Name = Name::new("reason");
+    pub static ref RESPONSE_ERROR: Name = Name::new("responseError");
+    pub static ref BAR_DATA: Name = Name::new("barData");
+    pub static ref BAR_TICK_DATA: Name = Name::new("barTickData");
+    pub static ref TICK_DATA: Name = Name::new("tickData");
+    pub static ref TOKEN_SUCCESS: Name = Name::new("TokenGenerationSuccess");
+    pub static ref TOKEN_FAILURE: Name = Name::new("TokenGenerationFailure");
+    pub static ref AUTHORIZATION_SUCCESS: Name = Name::new("AuthorizationSuccess");
+    pub static ref AUTHORIZATION_FAILURE: Name = Name::new("AuthorizationFailure");
+    pub static ref REQUEST_FAILURE: Name = Name::new("RequestFailure");
 }
 
 pub struct Name(pub(crate) *mut blpapi_Name_t);
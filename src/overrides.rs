@@ -0,0 +1,107 @@
+use crate::{element::Element, request::Request, Error};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Overrides(Vec<(String, String)>);
+
+impl Overrides {
+    pub fn new() -> Self {
+        Overrides::default()
+    }
+
+    pub fn with<F: Into<String>, V: ToString>(mut self, field: F, value: V) -> Self {
+        self.set(field, value);
+        self
+    }
+
+    pub fn set<F: Into<String>, V: ToString>(&mut self, field: F, value: V) {
+        let field: String = field.into();
+        let value: String = value.to_string();
+
+        match self.0.iter_mut().find(|(f, _)| *f == field) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((field, value)),
+        }
+    }
+
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.0.iter().find(|(f, _)| f == field).map(|(_, v)| &v[..])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(f, v)| (&f[..], &v[..]))
+    }
+
+    pub fn merge(mut self, other: &Overrides) -> Self {
+        for (field, value) in other.iter() {
+            self.set(field, value);
+        }
+
+        self
+    }
+
+    pub(crate) fn apply(&self, request: &mut Request) -> Result<(), Error> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        let mut overrides: Element = request
+            .element()
+            .get_element("overrides")
+            .ok_or_else(|| Error::NotFound("overrides".to_owned()))?;
+
+        for (field, value) in self.iter() {
+            let mut element: Element = overrides.append_element()?;
+
+            element.set("fieldId", field)?;
+            element.set("value", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: Into<String>, V: ToString> FromIterator<(F, V)> for Overrides {
+    fn from_iter<I: IntoIterator<Item = (F, V)>>(iter: I) -> Self {
+        let mut overrides: Overrides = Overrides::new();
+
+        for (field, value) in iter {
+            overrides.set(field, value);
+        }
+
+        overrides
+    }
+}
+
+impl From<&[(&str, &str)]> for Overrides {
+    fn from(overrides: &[(&str, &str)]) -> Self {
+        overrides.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_overrides() {
+        let defaults: Overrides = Overrides::from(&[("EQY_FUND_CRNCY", "USD")][..]);
+
+        let overrides: Overrides = defaults.merge(
+            &Overrides::new()
+                .with("EQY_FUND_CRNCY", "EUR")
+                .with("BEST_FPERIOD_OVERRIDE", "1BF"),
+        );
+
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides.get("EQY_FUND_CRNCY"), Some("EUR"));
+        assert_eq!(overrides.get("BEST_FPERIOD_OVERRIDE"), Some("1BF"));
+    }
+}
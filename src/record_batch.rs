@@ -0,0 +1,1065 @@
+use crate::{
+    batch::Batch,
+    datetime::HighPrecisionDatetime,
+    element::{DataType as ElementType, Element},
+    event::Event,
+    intraday::{self, BarOptions, TickOptions},
+    name,
+    overrides::Overrides,
+    schema::SchemaElementDefinition,
+    service::Service,
+    session::{self, Events, HistOptions, SessionSync},
+    Error,
+};
+use arrow_array::{
+    builder::BooleanBuilder, types::*, Array, ArrayRef, ListArray, NullArray, PrimitiveArray,
+    RecordBatch, StringArray, StructArray,
+};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::sync::Arc;
+
+const SECURITY: &str = "security";
+const UTC: &str = "UTC";
+const MAX_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    RefData,
+    HistData,
+    BarData(String),
+    TickData(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null(DataType),
+    Bool(bool),
+    Int32(i32),
+    Int64(i64),
+    Float64(f64),
+    String(String),
+    Date(NaiveDate),
+    Datetime(NaiveDateTime, bool),
+    Time(NaiveTime),
+    List(Vec<Value>),
+    Struct(Vec<(String, Value)>),
+}
+
+const NULL: &Value = &Value::Null(DataType::Null);
+
+impl Value {
+    fn from_element(element: &Element) -> Value {
+        if element.is_array() {
+            return Value::List(
+                (0..element.num_values())
+                    .map(|index| Value::from_element_at(element, index))
+                    .collect(),
+            );
+        }
+
+        if element.is_null() {
+            return Value::Null(value_type(element));
+        }
+
+        Value::from_element_at(element, 0)
+    }
+
+    fn from_element_at(element: &Element, index: usize) -> Value {
+        if element.is_null_value(index) {
+            return Value::Null(value_type(element));
+        }
+
+        let value: Option<Value> = match element.datatype() {
+            ElementType::Bool => element.get_at(index).map(Value::Bool),
+            ElementType::Char | ElementType::Byte | ElementType::Int32 => {
+                element.get_at(index).map(Value::Int32)
+            }
+            ElementType::Int64 => element.get_at(index).map(Value::Int64),
+            ElementType::Float32 | ElementType::Float64 | ElementType::Decimal => {
+                element.get_at(index).map(Value::Float64)
+            }
+            ElementType::String | ElementType::ByteArray | ElementType::Enumeration => {
+                element.get_at(index).map(Value::String)
+            }
+            ElementType::Date | ElementType::Time | ElementType::Datetime => element
+                .get_at::<HighPrecisionDatetime>(index)
+                .and_then(Value::from_datetime),
+            ElementType::Sequence => element.get_at::<Element>(index).map(|sequence| {
+                Value::Struct(
+                    sequence
+                        .elements()
+                        .map(|e| (e.string_name(), Value::from_element(&e)))
+                        .collect(),
+                )
+            }),
+            ElementType::Choice => element
+                .get_at::<Element>(index)
+                .and_then(|e| e.get_choice())
+                .map(|choice| {
+                    Value::Struct(vec![(choice.string_name(), Value::from_element(&choice))])
+                }),
+            _ => None,
+        };
+
+        value.unwrap_or_else(|| Value::Null(value_type(element)))
+    }
+
+    fn from_datetime(datetime: HighPrecisionDatetime) -> Option<Value> {
+        let inner = datetime.datetime();
+
+        match (inner.has_date(), inner.has_time()) {
+            (true, true) => {
+                let naive: NaiveDateTime = datetime.try_into().ok()?;
+
+                // Offsets differ from value to value, so timestamps are stored in UTC.
+                match datetime.offset() {
+                    Some(offset) => Some(Value::Datetime(naive - offset, true)),
+                    None => Some(Value::Datetime(naive, false)),
+                }
+            }
+            (true, false) => inner.try_into().ok().map(Value::Date),
+            (false, true) => datetime.try_into().ok().map(Value::Time),
+            (false, false) => None,
+        }
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Value::Null(data_type) => data_type.clone(),
+            Value::Bool(_) => DataType::Boolean,
+            Value::Int32(_) => DataType::Int32,
+            Value::Int64(_) => DataType::Int64,
+            Value::Float64(_) => DataType::Float64,
+            Value::String(_) => DataType::Utf8,
+            Value::Date(_) => DataType::Date32,
+            Value::Datetime(_, utc) => {
+                DataType::Timestamp(TimeUnit::Microsecond, utc.then(|| UTC.into()))
+            }
+            Value::Time(_) => DataType::Time64(TimeUnit::Microsecond),
+            Value::List(values) => DataType::new_list(data_type(values.iter()), true),
+            Value::Struct(entries) => DataType::Struct(
+                entries
+                    .iter()
+                    .map(|(name, value)| Field::new(name, value.data_type(), true))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn get(&self, name: &str) -> &Value {
+        match self {
+            Value::Struct(entries) => entries
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v)
+                .unwrap_or(NULL),
+            _ => NULL,
+        }
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            Value::Null(_) => write!(f, "null"),
+            Value::Bool(v) => write!(f, "{}", v),
+            Value::Int32(v) => write!(f, "{}", v),
+            Value::Int64(v) => write!(f, "{}", v),
+            Value::Float64(v) => write!(f, "{}", v),
+            Value::String(v) => write!(f, "{}", v),
+            Value::Date(v) => write!(f, "{}", v),
+            Value::Datetime(v, _) => write!(f, "{}", v),
+            Value::Time(v) => write!(f, "{}", v),
+            Value::List(values) => {
+                let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+
+                write!(f, "[{}]", values.join(", "))
+            }
+            Value::Struct(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect();
+
+                write!(f, "{{{}}}", entries.join(", "))
+            }
+        }
+    }
+}
+
+fn data_type<'a, I: Iterator<Item = &'a Value>>(values: I) -> DataType {
+    values.map(Value::data_type).fold(DataType::Null, merge)
+}
+
+fn merge(a: DataType, b: DataType) -> DataType {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (DataType::Null, b) => b,
+        (a, DataType::Null) => a,
+        (DataType::Int32, DataType::Int64) | (DataType::Int64, DataType::Int32) => DataType::Int64,
+        (DataType::Int32 | DataType::Int64, DataType::Float64)
+        | (DataType::Float64, DataType::Int32 | DataType::Int64) => DataType::Float64,
+        (DataType::Timestamp(unit, _), DataType::Timestamp(_, _)) => {
+            DataType::Timestamp(unit, Some(UTC.into()))
+        }
+        (DataType::List(a), DataType::List(b)) => {
+            DataType::new_list(merge(a.data_type().clone(), b.data_type().clone()), true)
+        }
+        (DataType::Struct(a), DataType::Struct(b)) => {
+            let mut fields: Vec<Field> = a.iter().map(|f| f.as_ref().clone()).collect();
+
+            for field in b.iter() {
+                match fields.iter_mut().find(|f| f.name() == field.name()) {
+                    Some(existing) => {
+                        let merged: DataType =
+                            merge(existing.data_type().clone(), field.data_type().clone());
+
+                        *existing = Field::new(field.name(), merged, true);
+                    }
+                    None => fields.push(field.as_ref().clone()),
+                }
+            }
+
+            DataType::Struct(fields.into())
+        }
+        _ => DataType::Utf8,
+    }
+}
+
+fn scalar_type(datatype: ElementType) -> DataType {
+    match datatype {
+        ElementType::Bool => DataType::Boolean,
+        ElementType::Char | ElementType::Byte | ElementType::Int32 => DataType::Int32,
+        ElementType::Int64 => DataType::Int64,
+        ElementType::Float32 | ElementType::Float64 | ElementType::Decimal => DataType::Float64,
+        ElementType::String | ElementType::ByteArray | ElementType::Enumeration => DataType::Utf8,
+        ElementType::Date => DataType::Date32,
+        ElementType::Time => DataType::Time64(TimeUnit::Microsecond),
+        ElementType::Datetime => DataType::Timestamp(TimeUnit::Microsecond, None),
+        _ => DataType::Null,
+    }
+}
+
+// Type of a single value of `element`, so nulls keep the type the schema declares.
+fn value_type(element: &Element) -> DataType {
+    match element.datatype() {
+        ElementType::Sequence | ElementType::Choice => complex_type(&element.definition(), 0),
+        datatype => scalar_type(datatype),
+    }
+}
+
+fn definition_type(definition: &SchemaElementDefinition, depth: usize) -> DataType {
+    let datatype: DataType = match definition.type_definition().datatype() {
+        ElementType::Sequence | ElementType::Choice => complex_type(definition, depth),
+        datatype => scalar_type(datatype),
+    };
+
+    if definition.is_array() {
+        DataType::new_list(datatype, true)
+    } else {
+        datatype
+    }
+}
+
+fn complex_type(definition: &SchemaElementDefinition, depth: usize) -> DataType {
+    if depth == MAX_DEPTH {
+        return DataType::Utf8;
+    }
+
+    DataType::Struct(
+        definition
+            .type_definition()
+            .element_definitions()
+            .map(|d| Field::new(d.string_name(), definition_type(&d, depth + 1), true))
+            .collect(),
+    )
+}
+
+// Whether `array` can hold `value` without losing it.
+fn fits(value: &Value, data_type: &DataType) -> bool {
+    match (value, data_type) {
+        (Value::Null(_), _) | (_, DataType::Utf8) => true,
+        (Value::Bool(_), DataType::Boolean)
+        | (Value::Int32(_), DataType::Int32 | DataType::Int64 | DataType::Float64)
+        | (Value::Int64(_), DataType::Int64 | DataType::Float64)
+        | (Value::Float64(_), DataType::Float64)
+        | (Value::Date(_), DataType::Date32)
+        | (Value::Datetime(..), DataType::Timestamp(..))
+        | (Value::Time(_), DataType::Time64(_)) => true,
+        (Value::List(items), DataType::List(field)) => {
+            items.iter().all(|v| fits(v, field.data_type()))
+        }
+        (Value::Struct(entries), DataType::Struct(fields)) => entries.iter().all(|(name, v)| {
+            fields
+                .iter()
+                .find(|f| f.name() == name)
+                .is_some_and(|f| fits(v, f.data_type()))
+        }),
+        _ => false,
+    }
+}
+
+fn array(data_type: &DataType, values: &[&Value]) -> ArrayRef {
+    match data_type {
+        DataType::Boolean => {
+            let mut builder: BooleanBuilder = BooleanBuilder::with_capacity(values.len());
+
+            for value in values {
+                match value {
+                    Value::Bool(v) => builder.append_value(*v),
+                    _ => builder.append_null(),
+                }
+            }
+
+            Arc::new(builder.finish())
+        }
+        DataType::Int32 => primitive::<Int32Type, _>(values, |v| match v {
+            Value::Int32(v) => Some(*v),
+            _ => None,
+        }),
+        DataType::Int64 => primitive::<Int64Type, _>(values, |v| match v {
+            Value::Int32(v) => Some(*v as i64),
+            Value::Int64(v) => Some(*v),
+            _ => None,
+        }),
+        DataType::Float64 => primitive::<Float64Type, _>(values, |v| match v {
+            Value::Int32(v) => Some(*v as f64),
+            Value::Int64(v) => Some(*v as f64),
+            Value::Float64(v) => Some(*v),
+            _ => None,
+        }),
+        DataType::Date32 => primitive::<Date32Type, _>(values, |v| match v {
+            Value::Date(v) => Some(Date32Type::from_naive_date(*v)),
+            _ => None,
+        }),
+        DataType::Timestamp(TimeUnit::Microsecond, timezone) => Arc::new(
+            values
+                .iter()
+                .map(|v| match v {
+                    Value::Datetime(v, _) => Some(v.and_utc().timestamp_micros()),
+                    _ => None,
+                })
+                .collect::<PrimitiveArray<TimestampMicrosecondType>>()
+                .with_timezone_opt(timezone.clone()),
+        ),
+        DataType::Time64(TimeUnit::Microsecond) => {
+            primitive::<Time64MicrosecondType, _>(values, |v| match v {
+                Value::Time(v) => Some(
+                    v.num_seconds_from_midnight() as i64 * 1_000_000
+                        + v.nanosecond() as i64 / 1_000,
+                ),
+                _ => None,
+            })
+        }
+        DataType::List(field) => {
+            let items: Vec<&Value> = values
+                .iter()
+                .flat_map(|v| match v {
+                    Value::List(items) => items.iter().collect(),
+                    _ => Vec::new(),
+                })
+                .collect();
+
+            let lengths = values.iter().map(|v| match v {
+                Value::List(items) => items.len(),
+                _ => 0,
+            });
+
+            let nulls: Vec<bool> = values.iter().map(|v| matches!(v, Value::List(_))).collect();
+
+            Arc::new(ListArray::new(
+                field.clone(),
+                OffsetBuffer::from_lengths(lengths),
+                array(field.data_type(), &items),
+                Some(nulls.into()),
+            ))
+        }
+        DataType::Struct(fields) => {
+            let nulls: Vec<bool> = values
+                .iter()
+                .map(|v| matches!(v, Value::Struct(_)))
+                .collect();
+
+            if fields.is_empty() {
+                return Arc::new(StructArray::new_empty_fields(
+                    values.len(),
+                    Some(nulls.into()),
+                ));
+            }
+
+            let children: Vec<ArrayRef> = fields
+                .iter()
+                .map(|field| {
+                    let column: Vec<&Value> = values.iter().map(|v| v.get(field.name())).collect();
+
+                    array(field.data_type(), &column)
+                })
+                .collect();
+
+            Arc::new(StructArray::new(
+                fields.clone(),
+                children,
+                Some(nulls.into()),
+            ))
+        }
+        DataType::Utf8 => Arc::new(
+            values
+                .iter()
+                .map(|v| match v {
+                    Value::Null(_) => None,
+                    Value::String(v) => Some(v.clone()),
+                    v => Some(v.to_string()),
+                })
+                .collect::<StringArray>(),
+        ),
+        _ => Arc::new(NullArray::new(values.len())),
+    }
+}
+
+fn primitive<T, F>(values: &[&Value], f: F) -> ArrayRef
+where
+    T: ArrowPrimitiveType,
+    F: Fn(&Value) -> Option<T::Native>,
+    PrimitiveArray<T>: Array,
+{
+    Arc::new(values.iter().map(|v| f(v)).collect::<PrimitiveArray<T>>())
+}
+
+#[derive(Debug, Default)]
+pub struct RecordBatchBuilder {
+    columns: Vec<String>,
+    rows: Vec<Vec<(usize, Value)>>,
+    errors: Vec<Error>,
+    schema: Option<Arc<Schema>>,
+}
+
+impl RecordBatchBuilder {
+    pub fn new() -> Self {
+        RecordBatchBuilder::default()
+    }
+
+    pub fn with_columns(mut self, columns: &[&str]) -> Self {
+        for column in columns {
+            self.column(column);
+        }
+
+        self
+    }
+
+    pub fn with_schema(mut self, schema: Schema) -> Self {
+        self.schema = Some(Arc::new(schema));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    fn column(&mut self, name: &str) -> usize {
+        match self
+            .columns
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(name))
+        {
+            Some(index) => index,
+            None => {
+                self.columns.push(name.to_owned());
+
+                self.columns.len() - 1
+            }
+        }
+    }
+
+    pub(crate) fn append_row(&mut self, row: Vec<(String, Value)>) {
+        let row: Vec<(usize, Value)> = row
+            .into_iter()
+            .map(|(name, value)| (self.column(&name), value))
+            .collect();
+
+        self.rows.push(row);
+    }
+
+    pub fn append_element(&mut self, element: &Element) {
+        self.append_row(fields(None, element));
+    }
+
+    pub fn append_event(&mut self, event: &Event, response: &Response) -> Result<(), Error> {
+        for message in event.messages().map(|m| m.element()) {
+            match response {
+                Response::RefData => self.on_ref_data(&message),
+                Response::HistData => self.on_hist_data(&message),
+                Response::BarData(security) => {
+                    self.on_intraday(&message, security, &name::BAR_DATA, &name::BAR_TICK_DATA)?
+                }
+                Response::TickData(security) => {
+                    self.on_intraday(&message, security, &name::TICK_DATA, &name::TICK_DATA)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_ref_data(&mut self, message: &Element) {
+        if let Some(securities) = message.get_named_element(&name::SECURITY_DATA) {
+            for security in securities.values::<Element>() {
+                let ticker: String = match session::on_security(&security, &mut self.errors) {
+                    Some(ticker) => ticker,
+                    None => continue,
+                };
+
+                if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+                    self.append_row(self::fields(Some(ticker), &fields));
+                }
+            }
+        }
+    }
+
+    fn on_hist_data(&mut self, message: &Element) {
+        if let Some(security) = message.get_named_element(&name::SECURITY_DATA) {
+            let ticker: String = match session::on_security(&security, &mut self.errors) {
+                Some(ticker) => ticker,
+                None => return,
+            };
+
+            if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+                for point in fields.values::<Element>() {
+                    self.append_row(self::fields(Some(ticker.clone()), &point));
+                }
+            }
+        }
+    }
+
+    fn on_intraday(
+        &mut self,
+        message: &Element,
+        security: &str,
+        data: &name::Name,
+        rows: &name::Name,
+    ) -> Result<(), Error> {
+        if let Some(error) = message.get_named_element(&name::RESPONSE_ERROR) {
+            return Err(Error::security(security.to_owned(), error));
+        }
+
+        if let Some(data) = message
+            .get_named_element(data)
+            .and_then(|e| e.get_named_element(rows))
+        {
+            for row in data.values::<Element>() {
+                self.append_row(fields(Some(security.to_owned()), &row));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn schema(&self) -> Schema {
+        match &self.schema {
+            Some(schema) => schema.as_ref().clone(),
+            None => self.infer(),
+        }
+    }
+
+    fn infer(&self) -> Schema {
+        Schema::new(
+            self.columns
+                .iter()
+                .enumerate()
+                .map(|(index, name)| Field::new(name, self.data_type(index), true))
+                .collect::<Vec<Field>>(),
+        )
+    }
+
+    fn data_type(&self, column: usize) -> DataType {
+        data_type(
+            self.rows
+                .iter()
+                .flat_map(|row| row.iter().filter(|(c, _)| *c == column).map(|(_, v)| v)),
+        )
+    }
+
+    // Every column has a type, so later rows cannot change the schema.
+    fn is_resolved(&self) -> bool {
+        (0..self.columns.len()).all(|column| self.data_type(column) != DataType::Null)
+    }
+
+    fn pin(&mut self) {
+        if self.schema.is_none() {
+            self.schema = Some(Arc::new(self.infer()));
+        }
+    }
+
+    fn check(&mut self, schema: &Schema) {
+        for (index, column) in self.columns.iter().enumerate() {
+            let values = self
+                .rows
+                .iter()
+                .flat_map(|row| row.iter().filter(|(c, _)| *c == index).map(|(_, v)| v));
+
+            let field: Option<&Field> = schema
+                .fields()
+                .iter()
+                .find(|f| f.name().eq_ignore_ascii_case(column))
+                .map(|f| f.as_ref());
+
+            let lost: usize = match field {
+                Some(field) => values.filter(|v| !fits(v, field.data_type())).count(),
+                None => values.filter(|v| !matches!(v, Value::Null(_))).count(),
+            };
+
+            if lost == 0 {
+                continue;
+            }
+
+            let message: String = match field {
+                Some(field) => format!(
+                    "{} values of {} do not fit {}",
+                    lost,
+                    column,
+                    field.data_type()
+                ),
+                None => format!("{} values of {} are not in the schema", lost, column),
+            };
+
+            self.errors.push(Error::Conversion(message));
+        }
+    }
+
+    pub fn finish(&mut self) -> Result<Batch<RecordBatch>, Error> {
+        let schema: Arc<Schema> = match &self.schema {
+            Some(schema) => schema.clone(),
+            None => Arc::new(self.infer()),
+        };
+
+        self.check(&schema);
+
+        let columns: Vec<ArrayRef> = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let index: Option<usize> = self
+                    .columns
+                    .iter()
+                    .position(|c| c.eq_ignore_ascii_case(field.name()));
+
+                let values: Vec<&Value> = self
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .find(|(c, _)| Some(*c) == index)
+                            .map(|(_, v)| v)
+                            .unwrap_or(NULL)
+                    })
+                    .collect();
+
+                array(field.data_type(), &values)
+            })
+            .collect();
+
+        let batch: RecordBatch = RecordBatch::try_new_with_options(
+            schema,
+            columns,
+            &arrow_array::RecordBatchOptions::new().with_row_count(Some(self.rows.len())),
+        )
+        .map_err(|e| Error::Conversion(e.to_string()))?;
+
+        self.rows.clear();
+
+        Ok(Batch {
+            data: batch,
+            errors: std::mem::take(&mut self.errors),
+        })
+    }
+}
+
+fn fields(security: Option<String>, element: &Element) -> Vec<(String, Value)> {
+    security
+        .map(|s| (SECURITY.to_owned(), Value::String(s)))
+        .into_iter()
+        .chain(
+            element
+                .elements()
+                .map(|e| (e.string_name(), Value::from_element(&e))),
+        )
+        .collect()
+}
+
+pub struct RecordBatches<'a> {
+    events: Events<'a>,
+    response: Response,
+    builder: RecordBatchBuilder,
+}
+
+impl<'a> RecordBatches<'a> {
+    pub fn with_schema(mut self, schema: Schema) -> Self {
+        self.builder = std::mem::take(&mut self.builder).with_schema(schema);
+        self
+    }
+}
+
+impl<'a> Iterator for RecordBatches<'a> {
+    type Item = Result<Batch<RecordBatch>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for event in self.events.by_ref() {
+            let res: Result<(), Error> =
+                event.and_then(|event| self.builder.append_event(&event, &self.response));
+
+            if let Err(e) = res {
+                return Some(Err(e));
+            }
+
+            // Rows are held back until every column has a type, the schema is fixed from there on.
+            if !self.builder.is_empty()
+                && (self.builder.schema.is_some() || self.builder.is_resolved())
+            {
+                self.builder.pin();
+
+                return Some(self.builder.finish());
+            }
+        }
+
+        if !self.builder.is_empty() || !self.builder.errors.is_empty() {
+            self.builder.pin();
+
+            return Some(self.builder.finish());
+        }
+
+        None
+    }
+}
+
+impl<'a> Events<'a> {
+    pub fn record_batches(self, response: Response, fields: &[&str]) -> RecordBatches<'a> {
+        let builder: RecordBatchBuilder = builder(&response, fields);
+
+        RecordBatches {
+            events: self,
+            response,
+            builder,
+        }
+    }
+}
+
+fn builder(response: &Response, fields: &[&str]) -> RecordBatchBuilder {
+    let builder: RecordBatchBuilder = RecordBatchBuilder::new().with_columns(&[SECURITY]);
+
+    match response {
+        Response::HistData => builder.with_columns(&["date"]).with_columns(fields),
+        _ => builder.with_columns(fields),
+    }
+}
+
+impl SessionSync {
+    pub fn ref_data_record_batch<I>(
+        &mut self,
+        securities: I,
+        fields: &[&str],
+    ) -> Result<Batch<RecordBatch>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.ref_data_record_batch_with_overrides(securities, fields, &Overrides::default())
+    }
+
+    pub fn ref_data_record_batch_with_overrides<I>(
+        &mut self,
+        securities: I,
+        fields: &[&str],
+        overrides: &Overrides,
+    ) -> Result<Batch<RecordBatch>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = session::collect_securities(securities);
+        let mut builder: RecordBatchBuilder = builder(&Response::RefData, fields);
+
+        for request in session::ref_data_requests(&service, &securities, fields, overrides) {
+            for event in self.send(request?, None)? {
+                builder.append_event(&event?, &Response::RefData)?;
+            }
+        }
+
+        builder.finish()
+    }
+
+    pub fn hist_data_record_batch<I>(
+        &mut self,
+        securities: I,
+        fields: &[&str],
+        options: HistOptions,
+    ) -> Result<Batch<RecordBatch>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = session::collect_securities(securities);
+        let mut builder: RecordBatchBuilder = builder(&Response::HistData, fields);
+
+        for request in session::hist_data_requests(
+            &service,
+            &securities,
+            fields,
+            &options,
+            options.overrides(),
+        ) {
+            for event in self.send(request?, None)? {
+                builder.append_event(&event?, &Response::HistData)?;
+            }
+        }
+
+        builder.finish()
+    }
+
+    pub fn bar_data_record_batch(
+        &mut self,
+        security: &str,
+        options: BarOptions,
+    ) -> Result<Batch<RecordBatch>, Error> {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let response: Response = Response::BarData(security.to_owned());
+        let mut builder: RecordBatchBuilder = builder(&response, &[]);
+
+        for request in intraday::bar_data_requests(&service, security, &options) {
+            for event in self.send(request?, None)? {
+                builder.append_event(&event?, &response)?;
+            }
+        }
+
+        builder.finish()
+    }
+
+    pub fn tick_data_record_batch(
+        &mut self,
+        security: &str,
+        options: TickOptions,
+    ) -> Result<Batch<RecordBatch>, Error> {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let response: Response = Response::TickData(security.to_owned());
+        let mut builder: RecordBatchBuilder = builder(&response, &[]);
+
+        for request in intraday::tick_data_requests(&service, security, &options) {
+            for event in self.send(request?, None)? {
+                builder.append_event(&event?, &response)?;
+            }
+        }
+
+        builder.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+
+    fn dividend(amount: f64, date: u32) -> Value {
+        Value::Struct(vec![
+            ("Amount".to_owned(), Value::Float64(amount)),
+            (
+                "Ex-Date".to_owned(),
+                Value::Date(NaiveDate::from_ymd_opt(2024, 1, date).unwrap()),
+            ),
+        ])
+    }
+
+    #[test]
+    fn ref_data_batch() -> Result<(), Error> {
+        let mut builder: RecordBatchBuilder =
+            RecordBatchBuilder::new().with_columns(&[SECURITY, "PX_LAST", "DVD_HIST"]);
+
+        builder.append_row(vec![
+            (
+                SECURITY.to_owned(),
+                Value::String("IBM US Equity".to_owned()),
+            ),
+            ("PX_LAST".to_owned(), Value::Int32(140)),
+            (
+                "DVD_HIST".to_owned(),
+                Value::List(vec![dividend(1.5, 10), dividend(1.6, 20)]),
+            ),
+        ]);
+
+        builder.append_row(vec![
+            (
+                SECURITY.to_owned(),
+                Value::String("AAPL US Equity".to_owned()),
+            ),
+            ("PX_LAST".to_owned(), Value::Float64(180.5)),
+        ]);
+
+        let batch: RecordBatch = builder.finish()?.into_result()?;
+
+        assert_eq!(batch.num_rows(), 2);
+        assert!(builder.is_empty());
+
+        let schema = batch.schema();
+
+        assert_eq!(schema.field(1).data_type(), &DataType::Float64);
+
+        let dividends: &ListArray = batch.column(2).as_list::<i32>();
+
+        assert!(dividends.is_null(1));
+        assert_eq!(dividends.value(0).len(), 2);
+
+        match schema.field(2).data_type() {
+            DataType::List(item) => match item.data_type() {
+                DataType::Struct(fields) => {
+                    assert_eq!(fields[0].data_type(), &DataType::Float64);
+                    assert_eq!(fields[1].data_type(), &DataType::Date32);
+                }
+                other => panic!("unexpected item type {}", other),
+            },
+            other => panic!("unexpected column type {}", other),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_types() {
+        assert_eq!(merge(DataType::Null, DataType::Int32), DataType::Int32);
+        assert_eq!(merge(DataType::Int32, DataType::Int64), DataType::Int64);
+        assert_eq!(merge(DataType::Date32, DataType::Boolean), DataType::Utf8);
+
+        let values: Vec<Value> = vec![Value::List(vec![]), Value::List(vec![dividend(1., 1)])];
+
+        match data_type(values.iter()) {
+            DataType::List(item) => assert!(matches!(item.data_type(), DataType::Struct(_))),
+            other => panic!("unexpected type {}", other),
+        }
+    }
+
+    #[test]
+    fn streamed_schema() -> Result<(), Error> {
+        let mut builder: RecordBatchBuilder =
+            RecordBatchBuilder::new().with_columns(&["PX_LAST", "VOLUME"]);
+
+        builder.append_row(vec![("PX_LAST".to_owned(), Value::Null(DataType::Float64))]);
+
+        assert!(!builder.is_resolved());
+
+        builder.append_row(vec![("VOLUME".to_owned(), Value::Int64(10))]);
+
+        assert!(builder.is_resolved());
+
+        builder.pin();
+
+        let first: Batch<RecordBatch> = builder.finish()?;
+
+        assert!(first.errors.is_empty());
+        assert_eq!(first.schema().field(0).data_type(), &DataType::Float64);
+        assert_eq!(first.schema().field(1).data_type(), &DataType::Int64);
+
+        builder.append_row(vec![
+            ("PX_LAST".to_owned(), Value::Int32(140)),
+            ("VOLUME".to_owned(), Value::Float64(10.5)),
+            ("PX_OPEN".to_owned(), Value::Float64(139.5)),
+        ]);
+
+        let second: Batch<RecordBatch> = builder.finish()?;
+
+        assert_eq!(first.schema(), second.schema());
+        assert_eq!(
+            second.column(0).as_primitive::<Float64Type>().value(0),
+            140.
+        );
+        assert_eq!(second.errors.len(), 2);
+        assert!(second
+            .errors
+            .iter()
+            .all(|e| matches!(e, Error::Conversion(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_nulls() {
+        let values: Vec<Value> = vec![
+            Value::Null(DataType::Date32),
+            Value::List(vec![Value::Null(DataType::Float64)]),
+        ];
+
+        assert_eq!(data_type(values[..1].iter()), DataType::Date32);
+        assert_eq!(
+            values[1].data_type(),
+            DataType::new_list(DataType::Float64, true)
+        );
+        assert!(fits(&values[1], &values[1].data_type()));
+        assert!(!fits(&Value::Float64(1.5), &DataType::Int64));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn record_batches() -> Result<(), Error> {
+        use crate::mock::{MockElement, MockEvent, MockMessage, MockSession};
+
+        let security = |security: &str, fields: MockElement| {
+            MockMessage::new("ReferenceDataResponse").with(
+                "securityData",
+                vec![MockElement::new()
+                    .with("security", security)
+                    .with("fieldData", fields)],
+            )
+        };
+
+        let mut session: MockSession = MockSession::new()
+            .with(MockEvent::partial_response().with_message(security(
+                "IBM US Equity",
+                MockElement::new().with("PX_LAST", 140.5),
+            )))?
+            .with(
+                MockEvent::response().with_message(security(
+                    "AAPL US Equity",
+                    MockElement::new()
+                        .with("PX_LAST", 180.25)
+                        .with("VOLUME", 1000i64),
+                )),
+            )?;
+
+        let batches: Vec<Batch<RecordBatch>> = session
+            .events()
+            .record_batches(Response::RefData, &["PX_LAST", "VOLUME"])
+            .collect::<Result<_, Error>>()?;
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_rows(), 2);
+        assert_eq!(batches[0].schema().field(2).data_type(), &DataType::Int64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn utc_timestamps() {
+        let datetime = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+
+        let values: Vec<Value> = vec![
+            Value::Datetime(datetime, false),
+            Value::Datetime(datetime, true),
+        ];
+
+        assert_eq!(
+            values[0].data_type(),
+            DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert_eq!(
+            data_type(values.iter()),
+            DataType::Timestamp(TimeUnit::Microsecond, Some(UTC.into()))
+        );
+    }
+}
@@ -3,5 +3,77 @@ use crate::element::Element;
 pub trait RefData: Default {
     const FIELDS: &'static [&'static str];
 
+    const OVERRIDES: &'static [(&'static str, &'static str)] = &[];
+
     fn on_field(&mut self, field: &str, element: &Element);
 }
+
+pub trait FromElement: Sized {
+    fn from_element(element: &Element) -> Option<Self>;
+}
+
+#[doc(hidden)]
+pub fn column_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[doc(hidden)]
+#[cfg(feature = "cache")]
+#[macro_export]
+macro_rules! __cache_data {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "cache"))]
+#[macro_export]
+macro_rules! __cache_data {
+    ($($item:tt)*) => {};
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+
+    #[derive(Default, crate::RefData)]
+    #[bbg(overrides(EQY_FUND_CRNCY = "USD"))]
+    struct Security {
+        px_last: f64,
+        #[bbg(field = "NAME")]
+        security_name: String,
+        #[bbg(skip)]
+        _cached: bool,
+        #[bbg(default)]
+        volume: Option<i64>,
+    }
+
+    #[derive(Default, crate::FromElement)]
+    struct DividendRow {
+        #[bbg(field = "Ex-Date")]
+        ex_date: Option<chrono::NaiveDate>,
+        dividend_amount: f64,
+    }
+
+    #[derive(Default, crate::RefData)]
+    struct Dividends {
+        dvd_hist_all: Vec<DividendRow>,
+    }
+
+    #[test]
+    fn derive_fields() {
+        assert_eq!(Security::FIELDS, &["PX_LAST", "NAME", "VOLUME"]);
+        assert_eq!(Security::OVERRIDES, &[("EQY_FUND_CRNCY", "USD")]);
+        assert_eq!(Dividends::FIELDS, &["DVD_HIST_ALL"]);
+    }
+
+    #[test]
+    fn column_keys() {
+        assert_eq!(column_key("Dividend Amount"), column_key("DIVIDEND_AMOUNT"));
+        assert_eq!(column_key("Ex-Date"), "exdate");
+    }
+}
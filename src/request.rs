@@ -1,6 +1,6 @@
 use crate::bindings::*;
 use crate::{
-    element::{Element, SetValue},
+    element::{Element, SetValue, ToElement},
     name::Name,
     service::Service,
     Error,
@@ -12,6 +12,8 @@ pub struct Request {
     elements: *mut blpapi_Element_t,
 }
 
+unsafe impl Send for Request {}
+
 impl Request {
     pub fn new(service: &Service, operation: &str) -> Result<Self, Error> {
         let operation: CString = CString::new(operation).unwrap();
@@ -52,6 +54,21 @@ impl Request {
     }
 }
 
+pub trait Operation: ToElement {
+    const SERVICE: &'static str;
+    const OPERATION: &'static str;
+}
+
+impl Service {
+    pub fn create_operation_request<O: Operation>(&self, operation: &O) -> Result<Request, Error> {
+        let request: Request = self.create_request(O::OPERATION)?;
+
+        operation.to_element(&mut request.element())?;
+
+        Ok(request)
+    }
+}
+
 impl Drop for Request {
     fn drop(&mut self) {
         unsafe { blpapi_Request_destroy(self.ptr) }
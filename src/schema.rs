@@ -0,0 +1,570 @@
+use crate::bindings::*;
+use crate::{
+    datetime::Datetime,
+    element::{DataType, Element},
+    name::Name,
+    service::Service,
+    Error,
+};
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaStatus {
+    Active,
+    Deprecated,
+    Inactive,
+    PendingDeprecation,
+    Unknown,
+}
+
+impl From<c_int> for SchemaStatus {
+    fn from(v: c_int) -> Self {
+        match v as u32 {
+            BLPAPI_STATUS_ACTIVE => SchemaStatus::Active,
+            BLPAPI_STATUS_DEPRECATED => SchemaStatus::Deprecated,
+            BLPAPI_STATUS_INACTIVE => SchemaStatus::Inactive,
+            BLPAPI_STATUS_PENDING_DEPRECATION => SchemaStatus::PendingDeprecation,
+            _ => SchemaStatus::Unknown,
+        }
+    }
+}
+
+impl Service {
+    pub fn description(&self) -> String {
+        unsafe { string(blpapi_Service_description(self.0)) }
+    }
+
+    pub fn num_operations(&self) -> usize {
+        unsafe { blpapi_Service_numOperations(self.0) as usize }
+    }
+
+    pub fn get_operation(&self, name: &str) -> Result<Operation<'_>, Error> {
+        let name: CString = CString::new(name).unwrap();
+        let mut ptr: *mut blpapi_Operation_t = ptr::null_mut();
+
+        let res: i32 = unsafe {
+            blpapi_Service_getOperation(self.0, &mut ptr as *mut _, name.as_ptr(), ptr::null())
+        };
+
+        Error::check(res)?;
+
+        Ok(Operation::new(ptr))
+    }
+
+    pub fn get_operation_at(&self, index: usize) -> Option<Operation<'_>> {
+        let mut ptr: *mut blpapi_Operation_t = ptr::null_mut();
+
+        let res: i32 = unsafe { blpapi_Service_getOperationAt(self.0, &mut ptr as *mut _, index) };
+
+        if res == 0 {
+            Some(Operation::new(ptr))
+        } else {
+            None
+        }
+    }
+
+    pub fn operations(&self) -> impl Iterator<Item = Operation<'_>> {
+        (0..self.num_operations()).filter_map(move |i| self.get_operation_at(i))
+    }
+
+    pub fn num_event_definitions(&self) -> usize {
+        unsafe { blpapi_Service_numEventDefinitions(self.0) as usize }
+    }
+
+    pub fn get_event_definition(&self, name: &str) -> Result<SchemaElementDefinition<'_>, Error> {
+        let name: CString = CString::new(name).unwrap();
+        let mut ptr: *mut blpapi_SchemaElementDefinition_t = ptr::null_mut();
+
+        let res: i32 = unsafe {
+            blpapi_Service_getEventDefinition(
+                self.0,
+                &mut ptr as *mut _,
+                name.as_ptr(),
+                ptr::null(),
+            )
+        };
+
+        Error::check(res)?;
+
+        Ok(SchemaElementDefinition::new(ptr))
+    }
+
+    pub fn get_event_definition_at(&self, index: usize) -> Option<SchemaElementDefinition<'_>> {
+        let mut ptr: *mut blpapi_SchemaElementDefinition_t = ptr::null_mut();
+
+        let res: i32 =
+            unsafe { blpapi_Service_getEventDefinitionAt(self.0, &mut ptr as *mut _, index) };
+
+        if res == 0 {
+            Some(SchemaElementDefinition::new(ptr))
+        } else {
+            None
+        }
+    }
+
+    pub fn event_definitions(&self) -> impl Iterator<Item = SchemaElementDefinition<'_>> {
+        (0..self.num_event_definitions()).filter_map(move |i| self.get_event_definition_at(i))
+    }
+}
+
+impl Element {
+    pub fn definition(&self) -> SchemaElementDefinition<'_> {
+        unsafe { SchemaElementDefinition::new(blpapi_Element_definition(self.ptr)) }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Operation<'a> {
+    ptr: *mut blpapi_Operation_t,
+    _phantom: PhantomData<&'a Service>,
+}
+
+impl<'a> Operation<'a> {
+    fn new(ptr: *mut blpapi_Operation_t) -> Self {
+        Operation {
+            ptr,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        unsafe { string(blpapi_Operation_name(self.ptr)) }
+    }
+
+    pub fn description(&self) -> String {
+        unsafe { string(blpapi_Operation_description(self.ptr)) }
+    }
+
+    pub fn request_definition(&self) -> Result<SchemaElementDefinition<'a>, Error> {
+        let mut ptr: *mut blpapi_SchemaElementDefinition_t = ptr::null_mut();
+
+        let res: i32 = unsafe { blpapi_Operation_requestDefinition(self.ptr, &mut ptr as *mut _) };
+
+        Error::check(res)?;
+
+        Ok(SchemaElementDefinition::new(ptr))
+    }
+
+    pub fn num_response_definitions(&self) -> usize {
+        unsafe { blpapi_Operation_numResponseDefinitions(self.ptr) as usize }
+    }
+
+    pub fn response_definition(&self, index: usize) -> Option<SchemaElementDefinition<'a>> {
+        let mut ptr: *mut blpapi_SchemaElementDefinition_t = ptr::null_mut();
+
+        let res: i32 =
+            unsafe { blpapi_Operation_responseDefinition(self.ptr, &mut ptr as *mut _, index) };
+
+        if res == 0 {
+            Some(SchemaElementDefinition::new(ptr))
+        } else {
+            None
+        }
+    }
+
+    pub fn response_definitions(&self) -> impl Iterator<Item = SchemaElementDefinition<'a>> {
+        let operation: Operation<'a> = *self;
+
+        (0..self.num_response_definitions()).filter_map(move |i| operation.response_definition(i))
+    }
+}
+
+impl<'a> std::fmt::Debug for Operation<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Operation: '{}'...", self.name())
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct SchemaElementDefinition<'a> {
+    ptr: *mut blpapi_SchemaElementDefinition_t,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> SchemaElementDefinition<'a> {
+    fn new(ptr: *mut blpapi_SchemaElementDefinition_t) -> Self {
+        SchemaElementDefinition {
+            ptr,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> Name {
+        unsafe { Name(blpapi_SchemaElementDefinition_name(self.ptr)) }
+    }
+
+    pub fn string_name(&self) -> String {
+        self.name().to_string()
+    }
+
+    pub fn description(&self) -> String {
+        unsafe { string(blpapi_SchemaElementDefinition_description(self.ptr)) }
+    }
+
+    pub fn status(&self) -> SchemaStatus {
+        unsafe { blpapi_SchemaElementDefinition_status(self.ptr).into() }
+    }
+
+    pub fn type_definition(&self) -> SchemaTypeDefinition<'a> {
+        unsafe { SchemaTypeDefinition::new(blpapi_SchemaElementDefinition_type(self.ptr)) }
+    }
+
+    pub fn alternate_names(&self) -> Vec<String> {
+        unsafe {
+            (0..blpapi_SchemaElementDefinition_numAlternateNames(self.ptr))
+                .map(|i| Name(blpapi_SchemaElementDefinition_getAlternateName(self.ptr, i)))
+                .map(|name| name.to_string())
+                .collect()
+        }
+    }
+
+    pub fn min_values(&self) -> usize {
+        unsafe { blpapi_SchemaElementDefinition_minValues(self.ptr) }
+    }
+
+    pub fn max_values(&self) -> Option<usize> {
+        let max_values: usize = unsafe { blpapi_SchemaElementDefinition_maxValues(self.ptr) };
+
+        if max_values == BLPAPI_ELEMENTDEFINITION_UNBOUNDED as usize {
+            None
+        } else {
+            Some(max_values)
+        }
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.min_values() == 0
+    }
+
+    pub fn is_array(&self) -> bool {
+        self.max_values() != Some(1)
+    }
+}
+
+impl<'a> std::fmt::Debug for SchemaElementDefinition<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SchemaElementDefinition: '{}'...", self.string_name())
+    }
+}
+
+impl<'a> std::fmt::Display for SchemaElementDefinition<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let printed: String = print(|writer, stream| unsafe {
+            blpapi_SchemaElementDefinition_print(self.ptr, writer, stream, 0, 4)
+        });
+
+        f.write_str(&printed)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct SchemaTypeDefinition<'a> {
+    ptr: *mut blpapi_SchemaTypeDefinition_t,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> SchemaTypeDefinition<'a> {
+    fn new(ptr: *mut blpapi_SchemaTypeDefinition_t) -> Self {
+        SchemaTypeDefinition {
+            ptr,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> Name {
+        unsafe { Name(blpapi_SchemaTypeDefinition_name(self.ptr)) }
+    }
+
+    pub fn string_name(&self) -> String {
+        self.name().to_string()
+    }
+
+    pub fn description(&self) -> String {
+        unsafe { string(blpapi_SchemaTypeDefinition_description(self.ptr)) }
+    }
+
+    pub fn status(&self) -> SchemaStatus {
+        unsafe { blpapi_SchemaTypeDefinition_status(self.ptr).into() }
+    }
+
+    pub fn datatype(&self) -> DataType {
+        unsafe { blpapi_SchemaTypeDefinition_datatype(self.ptr).into() }
+    }
+
+    pub fn is_complex(&self) -> bool {
+        unsafe { blpapi_SchemaTypeDefinition_isComplexType(self.ptr) != 0 }
+    }
+
+    pub fn is_simple(&self) -> bool {
+        unsafe { blpapi_SchemaTypeDefinition_isSimpleType(self.ptr) != 0 }
+    }
+
+    pub fn is_enumeration(&self) -> bool {
+        unsafe { blpapi_SchemaTypeDefinition_isEnumerationType(self.ptr) != 0 }
+    }
+
+    pub fn num_element_definitions(&self) -> usize {
+        unsafe { blpapi_SchemaTypeDefinition_numElementDefinitions(self.ptr) }
+    }
+
+    pub fn element_definition(&self, name: &str) -> Option<SchemaElementDefinition<'a>> {
+        let name: CString = CString::new(name).unwrap();
+
+        let ptr: *mut blpapi_SchemaElementDefinition_t = unsafe {
+            blpapi_SchemaTypeDefinition_getElementDefinition(self.ptr, name.as_ptr(), ptr::null())
+        };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(SchemaElementDefinition::new(ptr))
+        }
+    }
+
+    pub fn element_definition_at(&self, index: usize) -> Option<SchemaElementDefinition<'a>> {
+        let ptr: *mut blpapi_SchemaElementDefinition_t =
+            unsafe { blpapi_SchemaTypeDefinition_getElementDefinitionAt(self.ptr, index) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(SchemaElementDefinition::new(ptr))
+        }
+    }
+
+    pub fn element_definitions(&self) -> impl Iterator<Item = SchemaElementDefinition<'a>> {
+        let type_definition: SchemaTypeDefinition<'a> = *self;
+
+        (0..self.num_element_definitions())
+            .filter_map(move |i| type_definition.element_definition_at(i))
+    }
+
+    pub fn enumeration(&self) -> Option<ConstantList<'a>> {
+        let ptr: *mut blpapi_ConstantList_t =
+            unsafe { blpapi_SchemaTypeDefinition_enumeration(self.ptr) };
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(ConstantList {
+                ptr,
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for SchemaTypeDefinition<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "SchemaTypeDefinition: '{}'...", self.string_name())
+    }
+}
+
+impl<'a> std::fmt::Display for SchemaTypeDefinition<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let printed: String = print(|writer, stream| unsafe {
+            blpapi_SchemaTypeDefinition_print(self.ptr, writer, stream, 0, 4)
+        });
+
+        f.write_str(&printed)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ConstantList<'a> {
+    ptr: *mut blpapi_ConstantList_t,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> ConstantList<'a> {
+    pub fn name(&self) -> Name {
+        unsafe { Name(blpapi_ConstantList_name(self.ptr)) }
+    }
+
+    pub fn description(&self) -> String {
+        unsafe { string(blpapi_ConstantList_description(self.ptr)) }
+    }
+
+    pub fn status(&self) -> SchemaStatus {
+        unsafe { blpapi_ConstantList_status(self.ptr).into() }
+    }
+
+    pub fn datatype(&self) -> DataType {
+        unsafe { blpapi_ConstantList_datatype(self.ptr).into() }
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { blpapi_ConstantList_numConstants(self.ptr) as usize }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, name: &str) -> Option<Constant<'a>> {
+        let name: CString = CString::new(name).unwrap();
+
+        let ptr: *mut blpapi_Constant_t =
+            unsafe { blpapi_ConstantList_getConstant(self.ptr, name.as_ptr(), ptr::null()) };
+
+        Constant::opt(ptr)
+    }
+
+    pub fn get_at(&self, index: usize) -> Option<Constant<'a>> {
+        let ptr: *mut blpapi_Constant_t =
+            unsafe { blpapi_ConstantList_getConstantAt(self.ptr, index) };
+
+        Constant::opt(ptr)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Constant<'a>> {
+        let constants: ConstantList<'a> = *self;
+
+        (0..self.len()).filter_map(move |i| constants.get_at(i))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Constant<'a> {
+    ptr: *mut blpapi_Constant_t,
+    _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Constant<'a> {
+    fn opt(ptr: *mut blpapi_Constant_t) -> Option<Self> {
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Constant {
+                ptr,
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    pub fn name(&self) -> Name {
+        unsafe { Name(blpapi_Constant_name(self.ptr)) }
+    }
+
+    pub fn string_name(&self) -> String {
+        self.name().to_string()
+    }
+
+    pub fn description(&self) -> String {
+        unsafe { string(blpapi_Constant_description(self.ptr)) }
+    }
+
+    pub fn status(&self) -> SchemaStatus {
+        unsafe { blpapi_Constant_status(self.ptr).into() }
+    }
+
+    pub fn datatype(&self) -> DataType {
+        unsafe { blpapi_Constant_datatype(self.ptr).into() }
+    }
+
+    pub fn as_string(&self) -> Option<String> {
+        let mut value: *const c_char = ptr::null();
+
+        unsafe {
+            let res: i32 = blpapi_Constant_getValueAsString(self.ptr, &mut value as *mut _);
+
+            if res == 0 {
+                Some(string(value))
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        let mut value: i64 = 0;
+
+        let res: i32 = unsafe { blpapi_Constant_getValueAsInt64(self.ptr, &mut value as *mut _) };
+
+        if res == 0 {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        let mut value: f64 = 0.;
+
+        let res: i32 = unsafe { blpapi_Constant_getValueAsFloat64(self.ptr, &mut value as *mut _) };
+
+        if res == 0 {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_datetime(&self) -> Option<Datetime> {
+        let mut value: Datetime = Datetime::default();
+
+        let res: i32 = unsafe { blpapi_Constant_getValueAsDatetime(self.ptr, &mut value.0) };
+
+        if res == 0 {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for Constant<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Constant: '{}'...", self.string_name())
+    }
+}
+
+pub(crate) unsafe fn string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
+
+unsafe extern "C" fn write_to_string(
+    data: *const c_char,
+    length: c_int,
+    stream: *mut c_void,
+) -> c_int {
+    let buffer: &mut Vec<u8> = &mut *(stream as *mut Vec<u8>);
+    let data: &[u8] = std::slice::from_raw_parts(data as *const u8, length as usize);
+
+    buffer.extend_from_slice(data);
+
+    0
+}
+
+pub(crate) fn print<F>(f: F) -> String
+where
+    F: FnOnce(blpapi_StreamWriter_t, *mut c_void) -> c_int,
+{
+    let mut buffer: Vec<u8> = Vec::new();
+
+    f(
+        Some(write_to_string),
+        &mut buffer as *mut Vec<u8> as *mut c_void,
+    );
+
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_status() {
+        assert_eq!(SchemaStatus::from(0), SchemaStatus::Active);
+        assert_eq!(SchemaStatus::from(3), SchemaStatus::PendingDeprecation);
+        assert_eq!(SchemaStatus::from(42), SchemaStatus::Unknown);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    datetime::Datetime,
+    element::{DataType, Element, GetValue},
+    message::Message,
+};
+use serde::ser::{Error as _, Serialize, SerializeMap, SerializeSeq, Serializer};
+
+impl Element {
+    pub fn to_json(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self)
+    }
+}
+
+impl Serialize for Element {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.is_array() {
+            let len: usize = self.num_values();
+            let mut seq = serializer.serialize_seq(Some(len))?;
+
+            for index in 0..len {
+                seq.serialize_element(&Value(self, index))?;
+            }
+
+            return seq.end();
+        }
+
+        if self.is_null() {
+            return serializer.serialize_none();
+        }
+
+        match self.datatype() {
+            DataType::Sequence => {
+                let mut map = serializer.serialize_map(Some(self.num_elements()))?;
+
+                for element in self.elements() {
+                    map.serialize_entry(&element.string_name(), &element)?;
+                }
+
+                map.end()
+            }
+            DataType::Choice => {
+                let choice: Element = self
+                    .get_choice()
+                    .ok_or_else(|| S::Error::custom("cannot read choice"))?;
+
+                let mut map = serializer.serialize_map(Some(1))?;
+
+                map.serialize_entry(&choice.string_name(), &choice)?;
+
+                map.end()
+            }
+            _ => Value(self, 0).serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Serialize for Message<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.element().serialize(serializer)
+    }
+}
+
+impl Serialize for Datetime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+struct Value<'a>(&'a Element, usize);
+
+impl<'a> Value<'a> {
+    fn get<V: GetValue, E: serde::ser::Error>(&self) -> Result<V, E> {
+        self.0
+            .get_at(self.1)
+            .ok_or_else(|| E::custom(format!("cannot read {}", self.0.string_name())))
+    }
+}
+
+impl<'a> Serialize for Value<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Value(element, index) = *self;
+
+        match element.datatype() {
+            DataType::Sequence | DataType::Choice => {
+                self.get::<Element, _>()?.serialize(serializer)
+            }
+            _ if element.is_null_value(index) => serializer.serialize_none(),
+            DataType::Bool => serializer.serialize_bool(self.get()?),
+            DataType::Char => serializer.serialize_char(self.get::<i8, _>()? as u8 as char),
+            DataType::Byte | DataType::Int32 => serializer.serialize_i32(self.get()?),
+            DataType::Int64 => serializer.serialize_i64(self.get()?),
+            DataType::Float32 => serializer.serialize_f32(self.get()?),
+            DataType::Float64 | DataType::Decimal => serializer.serialize_f64(self.get()?),
+            DataType::String | DataType::ByteArray | DataType::Enumeration => {
+                serializer.serialize_str(&self.get::<String, _>()?)
+            }
+            DataType::Date | DataType::Time | DataType::Datetime => {
+                self.get::<Datetime, _>()?.serialize(serializer)
+            }
+            DataType::CorrelationId | DataType::Unknown => serializer.serialize_none(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use crate::{
+        event::Event,
+        message::Message,
+        mock::{self, MockElement, MockEvent, MockMessage, MockValue},
+        Error,
+    };
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn serialize_sequence() -> Result<(), Error> {
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        let event: Event = MockEvent::response()
+            .with_message(
+                MockMessage::new("Quote")
+                    .with("security", "IBM US Equity")
+                    .with("date", date)
+                    .with("closes", vec![140.5, 141.25])
+                    .with("bid", MockValue::Null)
+                    .with(
+                        "fieldData",
+                        MockElement::new()
+                            .with("PX_LAST", 140.5)
+                            .with("ACTIVE", true),
+                    ),
+            )
+            .build()?;
+
+        let message: Message = event.messages().next().unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            json!({
+                "security": "IBM US Equity",
+                "date": "2024-01-02",
+                "closes": [140.5, 141.25],
+                "bid": null,
+                "fieldData": { "PX_LAST": 140.5, "ACTIVE": true },
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn serialize_choice() -> Result<(), Error> {
+        let event: Event = MockEvent::response()
+            .with_message(MockMessage::new("ReferenceDataResponse").with(
+                "securityData",
+                vec![MockElement::new()
+                    .with("security", "IBM US Equity")
+                    .with("fieldData", MockElement::new().with("PX_LAST", 140.5))],
+            ))
+            .build_for(&mock::deserialize_service(mock::REFDATA_SCHEMA)?)?;
+
+        let message: Message = event.messages().next().unwrap();
+
+        assert_eq!(
+            message.element().to_json().unwrap(),
+            json!({
+                "securityData": [{
+                    "security": "IBM US Equity",
+                    "fieldData": { "PX_LAST": 140.5 },
+                }],
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -4,6 +4,9 @@ use std::ffi::CStr;
 
 pub struct Service(pub(crate) *mut blpapi_Service_t);
 
+unsafe impl Send for Service {}
+unsafe impl Sync for Service {}
+
 impl Service {
     pub fn name(&self) -> String {
         let name: &CStr = unsafe { CStr::from_ptr(blpapi_Service_name(self.0)) };
@@ -1,42 +1,67 @@
 #![allow(dead_code)]
 
 use crate::{
+    batch::Batch,
     bindings::*,
     correlation_id::CorrelationId,
     element::Element,
     event::{Event, EventType},
+    identity::{self, Identity},
+    intraday::{self, Bar, BarOptions, Tick, TickOptions},
     name,
+    overrides::Overrides,
     ref_data::RefData,
     request::Request,
     service::Service,
     session_options::SessionOptions,
+    subscription::SubscriptionList,
     Error,
 };
+use chrono::NaiveDate;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::{ffi::CString, ptr};
 
+pub use crate::time_serie::TimeSerie;
+
 const MAX_PENDING_REQUEST: usize = 1024;
 const MAX_REFDATA_FIELDS: usize = 400;
 const MAX_HISTDATA_FIELDS: usize = 25;
 
+pub(crate) type EventHandler =
+    Option<unsafe extern "C" fn(*mut blpapi_Event, *mut blpapi_Session, *mut std::ffi::c_void)>;
+
 pub struct Session {
-    ptr: *mut blpapi_Session_t,
-    correlation_count: u64,
+    pub(crate) ptr: *mut blpapi_Session_t,
+    correlation_count: AtomicU64,
+    identity: Option<Identity>,
 }
 
+unsafe impl Send for Session {}
+unsafe impl Sync for Session {}
+
 impl Session {
     fn from_options(options: SessionOptions) -> Self {
-        let handler: Option<
-            unsafe extern "C" fn(*mut blpapi_Event, *mut blpapi_Session, *mut std::ffi::c_void),
-        > = None;
+        let handler: EventHandler = None;
         let dispatcher: *mut blpapi_EventDispatcher = ptr::null_mut();
         let user_data: *mut std::ffi::c_void = ptr::null_mut();
+
+        Session::with_handler(options, handler, dispatcher, user_data)
+    }
+
+    pub(crate) fn with_handler(
+        options: SessionOptions,
+        handler: EventHandler,
+        dispatcher: *mut blpapi_EventDispatcher,
+        user_data: *mut std::ffi::c_void,
+    ) -> Self {
         let ptr: *mut blpapi_Session =
             unsafe { blpapi_Session_create(options.0, handler, dispatcher, user_data) };
 
         Session {
             ptr,
-            correlation_count: 0,
+            correlation_count: AtomicU64::new(0),
+            identity: None,
         }
     }
 
@@ -73,13 +98,22 @@ impl Session {
     }
 
     pub fn send(
-        &mut self,
+        &self,
+        request: Request,
+        correlation_id: Option<CorrelationId>,
+    ) -> Result<CorrelationId, Error> {
+        self.send_as(request, self.identity.as_ref(), correlation_id)
+    }
+
+    pub fn send_as(
+        &self,
         request: Request,
+        identity: Option<&Identity>,
         correlation_id: Option<CorrelationId>,
     ) -> Result<CorrelationId, Error> {
         let mut correlation_id: CorrelationId =
             correlation_id.unwrap_or_else(|| self.new_correlation_id());
-        let identity: *mut blpapi_Identity = ptr::null_mut();
+        let identity: *mut blpapi_Identity = identity.map_or(ptr::null_mut(), |i| i.0);
         let event_queue: *mut blpapi_EventQueue = ptr::null_mut();
         let request_label: *mut i8 = ptr::null_mut();
         let request_label_len: i32 = 0;
@@ -101,12 +135,132 @@ impl Session {
         }
     }
 
-    fn new_correlation_id(&mut self) -> CorrelationId {
-        let id = CorrelationId::new_u64(self.correlation_count);
+    pub fn subscribe(&self, subscriptions: &SubscriptionList) -> Result<(), Error> {
+        self.subscribe_as(subscriptions, self.identity.as_ref())
+    }
+
+    pub fn subscribe_as(
+        &self,
+        subscriptions: &SubscriptionList,
+        identity: Option<&Identity>,
+    ) -> Result<(), Error> {
+        let identity: *const blpapi_Identity = identity.map_or(ptr::null(), |i| i.0);
+        let request_label: *const i8 = ptr::null();
+        let request_label_len: i32 = 0;
+
+        let res: i32 = unsafe {
+            blpapi_Session_subscribe(
+                self.ptr,
+                subscriptions.0,
+                identity,
+                request_label,
+                request_label_len,
+            )
+        };
+
+        Error::check(res)
+    }
+
+    pub fn unsubscribe(&self, subscriptions: &SubscriptionList) -> Result<(), Error> {
+        let request_label: *const i8 = ptr::null();
+        let request_label_len: i32 = 0;
+
+        let res: i32 = unsafe {
+            blpapi_Session_unsubscribe(self.ptr, subscriptions.0, request_label, request_label_len)
+        };
+
+        Error::check(res)
+    }
+
+    pub fn resubscribe(&self, subscriptions: &SubscriptionList) -> Result<(), Error> {
+        let request_label: *const i8 = ptr::null();
+        let request_label_len: i32 = 0;
+
+        let res: i32 = unsafe {
+            blpapi_Session_resubscribe(self.ptr, subscriptions.0, request_label, request_label_len)
+        };
+
+        Error::check(res)
+    }
+
+    pub fn new_correlation_id(&self) -> CorrelationId {
+        CorrelationId::new_u64(self.correlation_count.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub fn cancel(&self, correlation_ids: &[CorrelationId]) -> Result<(), Error> {
+        let ids: Vec<blpapi_CorrelationId_t> = correlation_ids.iter().map(|id| id.0).collect();
+        let request_label: *const i8 = ptr::null();
+        let request_label_len: i32 = 0;
+
+        let res: i32 = unsafe {
+            blpapi_AbstractSession_cancel(
+                blpapi_Session_getAbstractSession(self.ptr),
+                ids.as_ptr(),
+                ids.len(),
+                request_label,
+                request_label_len,
+            )
+        };
+
+        Error::check(res)
+    }
+
+    pub fn create_identity(&self) -> Identity {
+        Identity(unsafe { blpapi_Session_createIdentity(self.ptr) })
+    }
+
+    pub fn identity(&self) -> Option<&Identity> {
+        self.identity.as_ref()
+    }
+
+    pub fn set_identity(&mut self, identity: Option<Identity>) {
+        self.identity = identity;
+    }
+
+    pub fn generate_token(
+        &self,
+        correlation_id: Option<CorrelationId>,
+    ) -> Result<CorrelationId, Error> {
+        let mut correlation_id: CorrelationId =
+            correlation_id.unwrap_or_else(|| self.new_correlation_id());
+        let event_queue: *mut blpapi_EventQueue = ptr::null_mut();
+
+        let res: i32 = unsafe {
+            blpapi_Session_generateToken(self.ptr, &mut correlation_id.0 as *mut _, event_queue)
+        };
+
+        Error::check(res)?;
+
+        Ok(correlation_id)
+    }
+
+    pub fn send_authorization_request(
+        &self,
+        request: Request,
+        identity: &Identity,
+        correlation_id: Option<CorrelationId>,
+    ) -> Result<CorrelationId, Error> {
+        let mut correlation_id: CorrelationId =
+            correlation_id.unwrap_or_else(|| self.new_correlation_id());
+        let event_queue: *mut blpapi_EventQueue = ptr::null_mut();
+        let request_label: *const i8 = ptr::null();
+        let request_label_len: i32 = 0;
+
+        let res: i32 = unsafe {
+            blpapi_Session_sendAuthorizationRequest(
+                self.ptr,
+                request.ptr,
+                identity.0,
+                &mut correlation_id.0 as *mut _,
+                event_queue,
+                request_label,
+                request_label_len,
+            )
+        };
 
-        self.correlation_count += 1;
+        Error::check(res)?;
 
-        id
+        Ok(correlation_id)
     }
 }
 
@@ -124,7 +278,11 @@ impl SessionSync {
     }
 
     pub fn new() -> Result<Self, Error> {
-        let mut session = Self::from_options(SessionOptions::default());
+        let options: SessionOptions = SessionOptions::new()?;
+
+        crate::version::check()?;
+
+        let mut session = Self::from_options(options);
 
         session.start()?;
 
@@ -138,9 +296,10 @@ impl SessionSync {
         request: Request,
         correlation_id: Option<CorrelationId>,
     ) -> Result<Events, Error> {
-        let _id = (&mut *self as &mut Session).send(request, correlation_id)?;
+        let correlation_id: CorrelationId =
+            (&mut *self as &mut Session).send(request, correlation_id)?;
 
-        Ok(Events::new(self))
+        Ok(Events::new(self, Some(correlation_id)))
     }
 
     pub fn next_event(&mut self, timeout_ms: Option<u32>) -> Result<Event, Error> {
@@ -157,62 +316,98 @@ impl SessionSync {
         }
     }
 
-    pub fn ref_data<I, R>(&mut self, securities: I) -> Result<HashMap<String, R>, Error>
-    where
-        I: IntoIterator,
-        I::Item: AsRef<str>,
-        R: RefData,
-    {
-        let service = self.get_service("//blp/refdata")?;
-        let mut ref_data: HashMap<String, R> = HashMap::new();
-        let mut iter: <I as IntoIterator>::IntoIter = securities.into_iter();
+    pub fn token(&mut self) -> Result<String, Error> {
+        let correlation_id: CorrelationId = self.0.generate_token(None)?;
 
-        for fields in R::FIELDS.chunks(MAX_REFDATA_FIELDS) {
-            loop {
-                let mut request: Request = service.create_request("ReferenceDataRequest")?;
+        loop {
+            let event: Event = self.next_event(None)?;
 
-                let mut is_empty: bool = true;
+            if let Some(token) = identity::on_token(&event, &correlation_id) {
+                return token;
+            }
 
-                for security in iter.by_ref().take(MAX_PENDING_REQUEST / fields.len()) {
-                    request.append_named(&name::SECURITIES, security.as_ref())?;
-                    is_empty = false;
-                }
+            check_session_status(&event)?;
+        }
+    }
 
-                if is_empty {
-                    break;
-                }
+    pub fn authorize(&mut self) -> Result<Identity, Error> {
+        let token: String = self.token()?;
 
-                for field in fields {
-                    request.append_named(&name::FIELDS_NAME, *field)?;
-                }
+        self.authorize_token(&token)
+    }
 
-                for event in self.send(request, None)? {
-                    for message in event?.messages().map(|m| m.element()) {
-                        if let Some(securities) = message.get_named_element(&name::SECURITY_DATA) {
-                            for security in securities.values::<Element>() {
-                                let ticker: String = security
-                                    .get_named_element(&name::SECURITY_NAME)
-                                    .and_then(|s: Element| s.get_at(0))
-                                    .unwrap_or_else(String::new);
-
-                                if let Some(error) =
-                                    security.get_named_element(&name::SECURITY_ERROR)
-                                {
-                                    return Err(Error::security(ticker, error));
-                                }
-
-                                let entry: &mut R = ref_data.entry(ticker).or_default();
-
-                                if let Some(fields) = security.get_named_element(&name::FIELD_DATA)
-                                {
-                                    for field in fields.elements() {
-                                        entry.on_field(&field.string_name(), &field);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+    pub fn authorize_token(&mut self, token: &str) -> Result<Identity, Error> {
+        let mut request: Request = self.authorization_request()?;
+
+        request.append("token", token)?;
+
+        self.send_authorization(request)
+    }
+
+    pub fn authorize_user(&mut self, uuid: i32, ip_address: &str) -> Result<Identity, Error> {
+        let mut request: Request = self.authorization_request()?;
+
+        request.append("uuid", uuid)?;
+        request.append("ipAddress", ip_address)?;
+
+        self.send_authorization(request)
+    }
+
+    fn authorization_request(&mut self) -> Result<Request, Error> {
+        self.open_service("//blp/apiauth")?;
+
+        self.get_service("//blp/apiauth")?
+            .create_request("AuthorizationRequest")
+    }
+
+    fn send_authorization(&mut self, request: Request) -> Result<Identity, Error> {
+        let identity: Identity = self.create_identity();
+        let correlation_id: CorrelationId = self
+            .0
+            .send_authorization_request(request, &identity, None)?;
+
+        loop {
+            let event: Event = self.next_event(None)?;
+
+            if let Some(res) = identity::on_authorization(&event, &correlation_id) {
+                return res.map(|_| identity);
+            }
+
+            check_session_status(&event)?;
+        }
+    }
+
+    pub fn subscription_events(&mut self, timeout_ms: Option<u32>) -> SubscriptionEvents<'_> {
+        SubscriptionEvents::new(self, timeout_ms)
+    }
+
+    pub fn ref_data<I, R>(&mut self, securities: I) -> Result<Batch<HashMap<String, R>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: RefData,
+    {
+        self.ref_data_with_overrides(securities, &Overrides::default())
+    }
+
+    pub fn ref_data_with_overrides<I, R>(
+        &mut self,
+        securities: I,
+        overrides: &Overrides,
+    ) -> Result<Batch<HashMap<String, R>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: RefData,
+    {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = collect_securities(securities);
+        let overrides: Overrides = Overrides::from(R::OVERRIDES).merge(overrides);
+        let mut ref_data: Batch<HashMap<String, R>> = Batch::default();
+
+        for request in ref_data_requests(&service, &securities, R::FIELDS, &overrides) {
+            for event in self.send(request?, None)? {
+                on_ref_data(&event?, &mut ref_data)?;
             }
         }
 
@@ -223,81 +418,249 @@ impl SessionSync {
         &mut self,
         securities: I,
         options: HistOptions,
-    ) -> Result<HashMap<String, TimeSerie<R>>, Error>
+    ) -> Result<Batch<HashMap<String, TimeSerie<R>>>, Error>
     where
         I: IntoIterator,
         I::Item: AsRef<str>,
         R: RefData,
     {
-        let service = self.get_service("//blp/refdata")?;
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = collect_securities(securities);
+        let overrides: Overrides = Overrides::from(R::OVERRIDES).merge(options.overrides());
+        let mut hist_data: Batch<HashMap<String, TimeSerie<R>>> = Batch::default();
+
+        for request in hist_data_requests(&service, &securities, R::FIELDS, &options, &overrides) {
+            for event in self.send(request?, None)? {
+                on_hist_data(&event?, &mut hist_data)?;
+            }
+        }
 
-        let mut ref_data: HashMap<String, TimeSerie<R>> = HashMap::new();
-        let mut iter = securities.into_iter();
+        Ok(hist_data)
+    }
 
-        for fields in R::FIELDS.chunks(MAX_HISTDATA_FIELDS) {
-            loop {
-                let mut request = service.create_request("HistoricalDataRequest")?;
-                let mut is_empty = true;
+    pub fn bar_data(&mut self, security: &str, options: BarOptions) -> Result<Vec<Bar>, Error> {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let mut bars: Vec<Bar> = Vec::new();
 
-                for security in iter.by_ref().take(MAX_PENDING_REQUEST / fields.len()) {
-                    request.append_named(&name::SECURITIES, security.as_ref())?;
-                    is_empty = false;
-                }
+        for request in intraday::bar_data_requests(&service, security, &options) {
+            for event in self.send(request?, None)? {
+                intraday::on_bar_data(&event?, security, &mut bars)?;
+            }
+        }
 
-                if is_empty {
-                    break;
-                }
+        intraday::sort_bars(&mut bars);
 
-                for field in fields {
-                    request.append_named(&name::FIELDS_NAME, *field)?;
-                }
+        Ok(bars)
+    }
+
+    pub fn tick_data(&mut self, security: &str, options: TickOptions) -> Result<Vec<Tick>, Error> {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let mut ticks: Vec<Tick> = Vec::new();
+
+        for request in intraday::tick_data_requests(&service, security, &options) {
+            for event in self.send(request?, None)? {
+                intraday::on_tick_data(&event?, security, &mut ticks)?;
+            }
+        }
 
-                options.apply(&mut request)?;
+        Ok(ticks)
+    }
+}
+
+fn check_session_status(event: &Event) -> Result<(), Error> {
+    match event.event_type() {
+        EventType::SessionStatus
+            if event
+                .messages()
+                .any(|m| m.message_type() == *name::SESSION_TERMINATED) =>
+        {
+            Err(Error::SessionTerminated)
+        }
+        EventType::Timeout => Err(Error::TimeOut),
+        _ => Ok(()),
+    }
+}
 
-                for event in self.send(request, None)? {
-                    for message in event?.messages().map(|m| m.element()) {
-                        if let Some(security) = message.get_named_element(&name::SECURITY_DATA) {
-                            let ticker: String = security
-                                .get_named_element(&name::SECURITY_NAME)
-                                .and_then(|s: Element| s.get_at(0))
-                                .unwrap_or_else(|| String::new());
+pub(crate) fn collect_securities<I>(securities: I) -> Vec<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    securities
+        .into_iter()
+        .map(|s| s.as_ref().to_owned())
+        .collect()
+}
 
-                            if security.has_named_element(&name::SECURITY_ERROR) {
-                                break;
-                            }
+fn requests<'a>(
+    service: &'a Service,
+    operation: &'static str,
+    securities: &'a [String],
+    fields: &'a [&'a str],
+    max_fields: usize,
+    overrides: &'a Overrides,
+) -> impl Iterator<Item = Result<Request, Error>> + 'a {
+    fields.chunks(max_fields).flat_map(move |fields| {
+        let max_securities: usize = (MAX_PENDING_REQUEST / fields.len()).max(1);
+
+        securities.chunks(max_securities).map(move |securities| {
+            let mut request: Request = service.create_request(operation)?;
+
+            for security in securities {
+                request.append_named(&name::SECURITIES, &security[..])?;
+            }
 
-                            if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
-                                let entry: &mut TimeSerie<R> =
-                                    ref_data.entry(ticker).or_insert_with(|| {
-                                        let len: usize = fields.num_values();
+            for field in fields {
+                request.append_named(&name::FIELDS_NAME, *field)?;
+            }
 
-                                        TimeSerie::<_>::with_capacity(len)
-                                    });
+            overrides.apply(&mut request)?;
 
-                                for points in fields.values::<Element>() {
-                                    let mut value = R::default();
+            Ok(request)
+        })
+    })
+}
 
-                                    for field in points.elements() {
-                                        let name = &field.string_name();
+pub(crate) fn ref_data_requests<'a>(
+    service: &'a Service,
+    securities: &'a [String],
+    fields: &'a [&'a str],
+    overrides: &'a Overrides,
+) -> impl Iterator<Item = Result<Request, Error>> + 'a {
+    requests(
+        service,
+        "ReferenceDataRequest",
+        securities,
+        fields,
+        MAX_REFDATA_FIELDS,
+        overrides,
+    )
+}
 
-                                        if name == "date" {
-                                            // entry.dates.extend(field.get_at(0));
-                                        } else {
-                                            value.on_field(name, &field);
-                                        }
-                                    }
+pub(crate) fn hist_data_requests<'a>(
+    service: &'a Service,
+    securities: &'a [String],
+    fields: &'a [&'a str],
+    options: &'a HistOptions,
+    overrides: &'a Overrides,
+) -> impl Iterator<Item = Result<Request, Error>> + 'a {
+    requests(
+        service,
+        "HistoricalDataRequest",
+        securities,
+        fields,
+        MAX_HISTDATA_FIELDS,
+        overrides,
+    )
+    .map(move |request| {
+        let mut request: Request = request?;
+
+        options.apply(&mut request)?;
+
+        Ok(request)
+    })
+}
 
-                                    entry.values.push(value);
-                                }
-                            }
-                        }
+fn on_field_exceptions(security: &Element, ticker: &str, errors: &mut Vec<Error>) {
+    if !security.has_named_element(&name::FIELD_EXCEPTIONS) {
+        return;
+    }
+
+    if let Some(exceptions) = security.get_named_element(&name::FIELD_EXCEPTIONS) {
+        for exception in exceptions.values::<Element>() {
+            errors.push(Error::field(ticker.to_owned(), exception));
+        }
+    }
+}
+
+pub(crate) fn on_security(security: &Element, errors: &mut Vec<Error>) -> Option<String> {
+    let ticker: String = security
+        .get_named_element(&name::SECURITY_NAME)
+        .and_then(|s: Element| s.get_at(0))
+        .unwrap_or_default();
+
+    if security.has_named_element(&name::SECURITY_ERROR) {
+        if let Some(error) = security.get_named_element(&name::SECURITY_ERROR) {
+            errors.push(Error::security(ticker, error));
+        }
+
+        return None;
+    }
+
+    on_field_exceptions(security, &ticker, errors);
+
+    Some(ticker)
+}
+
+pub(crate) fn on_ref_data<R: RefData>(
+    event: &Event,
+    ref_data: &mut Batch<HashMap<String, R>>,
+) -> Result<(), Error> {
+    for message in event.messages().map(|m| m.element()) {
+        if let Some(securities) = message.get_named_element(&name::SECURITY_DATA) {
+            for security in securities.values::<Element>() {
+                let ticker: String = match on_security(&security, &mut ref_data.errors) {
+                    Some(ticker) => ticker,
+                    None => continue,
+                };
+
+                let entry: &mut R = ref_data.data.entry(ticker).or_default();
+
+                if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+                    for field in fields.elements() {
+                        entry.on_field(&field.string_name(), &field);
                     }
                 }
             }
         }
+    }
 
-        Ok(ref_data)
+    Ok(())
+}
+
+pub(crate) fn on_hist_data<R: RefData>(
+    event: &Event,
+    hist_data: &mut Batch<HashMap<String, TimeSerie<R>>>,
+) -> Result<(), Error> {
+    for message in event.messages().map(|m| m.element()) {
+        if let Some(security) = message.get_named_element(&name::SECURITY_DATA) {
+            let ticker: String = match on_security(&security, &mut hist_data.errors) {
+                Some(ticker) => ticker,
+                None => continue,
+            };
+
+            if let Some(fields) = security.get_named_element(&name::FIELD_DATA) {
+                let entry: &mut TimeSerie<R> = hist_data.data.entry(ticker).or_insert_with(|| {
+                    let len: usize = fields.num_values();
+
+                    TimeSerie::<_>::with_capacity(len)
+                });
+
+                for points in fields.values::<Element>() {
+                    let date: NaiveDate = match points.element_value::<NaiveDate>("date") {
+                        Some(date) => date,
+                        None => {
+                            log::warn!("Missing date in historical data...");
+
+                            continue;
+                        }
+                    };
+
+                    let value: &mut R = entry.entry(date);
+
+                    for field in points.elements() {
+                        let name = &field.string_name();
+
+                        if name != "date" {
+                            value.on_field(name, &field);
+                        }
+                    }
+                }
+            }
+        }
     }
+
+    Ok(())
 }
 
 impl std::ops::Deref for SessionSync {
@@ -313,19 +676,64 @@ impl std::ops::DerefMut for SessionSync {
     }
 }
 
+pub(crate) trait EventSource {
+    fn next_event(&mut self, timeout_ms: Option<u32>) -> Result<Event, Error>;
+
+    fn cancel(&mut self, _correlation_id: &CorrelationId) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl EventSource for SessionSync {
+    fn next_event(&mut self, timeout_ms: Option<u32>) -> Result<Event, Error> {
+        SessionSync::next_event(self, timeout_ms)
+    }
+
+    fn cancel(&mut self, correlation_id: &CorrelationId) -> Result<(), Error> {
+        Session::cancel(self, &[*correlation_id])
+    }
+}
+
 pub struct Events<'a> {
-    session: &'a mut SessionSync,
+    session: &'a mut dyn EventSource,
+    correlation_id: Option<CorrelationId>,
     exit: bool,
 }
 
 impl<'a> Events<'a> {
-    fn new(session: &'a mut SessionSync) -> Self {
+    pub(crate) fn new(
+        session: &'a mut dyn EventSource,
+        correlation_id: Option<CorrelationId>,
+    ) -> Self {
         Events {
             session,
+            correlation_id,
             exit: false,
         }
     }
 
+    pub fn correlation_id(&self) -> Option<CorrelationId> {
+        self.correlation_id
+    }
+
+    pub fn cancel(mut self) -> Result<(), Error> {
+        self.exit = true;
+
+        match self.correlation_id {
+            Some(correlation_id) => self.session.cancel(&correlation_id),
+            None => Ok(()),
+        }
+    }
+
+    fn is_ours(&self, event: &Event) -> bool {
+        match &self.correlation_id {
+            Some(correlation_id) => event
+                .messages()
+                .any(|m| m.has_correlation_id(correlation_id)),
+            None => true,
+        }
+    }
+
     fn try_next(&mut self) -> Result<Option<Event>, Error> {
         if self.exit {
             return Ok(None);
@@ -335,15 +743,27 @@ impl<'a> Events<'a> {
             let event_type: EventType = event.event_type();
 
             match event_type {
-                EventType::PartialResponse => return Ok(Some(event)),
-                EventType::Response => {
+                EventType::PartialResponse if self.is_ours(&event) => return Ok(Some(event)),
+                EventType::Response if self.is_ours(&event) => {
                     self.exit = true;
                     return Ok(Some(event));
                 }
+                EventType::RequestStatus if self.is_ours(&event) => {
+                    let failure: Option<String> = event
+                        .messages()
+                        .find(|m| m.message_type() == *name::REQUEST_FAILURE)
+                        .map(|m| m.failure_reason());
+
+                    if let Some(reason) = failure {
+                        self.exit = true;
+                        return Err(Error::RequestFailure(reason));
+                    }
+                }
                 EventType::SessionStatus => {
                     if event.messages().map(|m| m.message_type()).any(|m| {
                         m == *name::SESSION_TERMINATED || m == *name::SESSION_STARTUP_FAILURE
                     }) {
+                        self.exit = true;
                         return Ok(None);
                     }
                 }
@@ -356,6 +776,20 @@ impl<'a> Events<'a> {
     }
 }
 
+impl<'a> Drop for Events<'a> {
+    fn drop(&mut self) {
+        if self.exit {
+            return;
+        }
+
+        if let Some(correlation_id) = self.correlation_id {
+            if let Err(e) = self.session.cancel(&correlation_id) {
+                log::debug!("Failed to cancel request: {}...", e);
+            }
+        }
+    }
+}
+
 impl<'a> Iterator for Events<'a> {
     type Item = Result<Event, Error>;
 
@@ -364,7 +798,60 @@ impl<'a> Iterator for Events<'a> {
     }
 }
 
-#[derive(Debug, Default)]
+pub struct SubscriptionEvents<'a> {
+    session: &'a mut dyn EventSource,
+    timeout_ms: Option<u32>,
+    exit: bool,
+}
+
+impl<'a> SubscriptionEvents<'a> {
+    pub(crate) fn new(session: &'a mut dyn EventSource, timeout_ms: Option<u32>) -> Self {
+        SubscriptionEvents {
+            session,
+            timeout_ms,
+            exit: false,
+        }
+    }
+
+    fn try_next(&mut self) -> Result<Option<Event>, Error> {
+        if self.exit {
+            return Ok(None);
+        }
+
+        loop {
+            let event: Event = self.session.next_event(self.timeout_ms)?;
+
+            match event.event_type() {
+                EventType::SubscriptionData | EventType::SubscriptionStatus => {
+                    return Ok(Some(event))
+                }
+                EventType::SessionStatus
+                    if event.messages().map(|m| m.message_type()).any(|m| {
+                        m == *name::SESSION_TERMINATED || m == *name::SESSION_STARTUP_FAILURE
+                    }) =>
+                {
+                    self.exit = true;
+
+                    return Ok(None);
+                }
+
+                EventType::Timeout => return Err(Error::TimeOut),
+
+                _ => (),
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for SubscriptionEvents<'a> {
+    type Item = Result<Event, Error>;
+
+    fn next(&mut self) -> Option<Result<Event, Error>> {
+        self.try_next().transpose()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct HistOptions {
     start_date: String,
     end_date: String,
@@ -372,6 +859,7 @@ pub struct HistOptions {
     periodicity_selection: Option<PeriodicitySelection>,
     max_data_points: Option<i32>,
     currency: Option<String>,
+    overrides: Overrides,
 }
 
 impl HistOptions {
@@ -409,6 +897,47 @@ impl HistOptions {
         self
     }
 
+    pub fn with_overrides(mut self, overrides: Overrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    pub fn overrides(&self) -> &Overrides {
+        &self.overrides
+    }
+
+    pub(crate) fn dates(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let start: NaiveDate = NaiveDate::parse_from_str(&self.start_date, "%Y%m%d").ok()?;
+        let end: NaiveDate = NaiveDate::parse_from_str(&self.end_date, "%Y%m%d").ok()?;
+
+        Some((start, end))
+    }
+
+    pub(crate) fn with_dates(&self, start: NaiveDate, end: NaiveDate) -> Self {
+        HistOptions {
+            start_date: start.format("%Y%m%d").to_string(),
+            end_date: end.format("%Y%m%d").to_string(),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn is_cacheable(&self) -> bool {
+        self.max_data_points.is_none()
+            && matches!(
+                self.periodicity_selection,
+                None | Some(PeriodicitySelection::Daily)
+            )
+    }
+
+    pub(crate) fn key(&self) -> String {
+        format!(
+            "{}/{}/{}",
+            self.periodicity_selection.map_or("", |p| p.as_str()),
+            self.periodicity_adjustment.map_or("", |p| p.as_str()),
+            self.currency.as_deref().unwrap_or("")
+        )
+    }
+
     fn apply(&self, request: &mut Request) -> Result<(), Error> {
         let mut element = request.element();
 
@@ -435,21 +964,6 @@ impl HistOptions {
     }
 }
 
-#[derive(Default, Debug)]
-pub struct TimeSerie<R> {
-    pub values: Vec<R>,
-    dates: Vec<chrono::NaiveDate>,
-}
-
-impl<R> TimeSerie<R> {
-    pub fn with_capacity(capacity: usize) -> Self {
-        TimeSerie {
-            dates: Vec::with_capacity(capacity),
-            values: Vec::with_capacity(capacity),
-        }
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 pub enum PeriodicityAdjustment {
     Actual,
@@ -495,6 +1009,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
     fn send_request() -> Result<(), Error> {
         let mut _session: SessionSync = SessionOptions::default()
             .with_server_host("localhost")?
@@ -503,4 +1018,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn hist_options_cacheable() {
+        let options: HistOptions = HistOptions::new("20240101", "20240131");
+
+        assert!(options.is_cacheable());
+        assert!(options
+            .clone()
+            .with_periodicity_selection(PeriodicitySelection::Daily)
+            .is_cacheable());
+        assert!(!options
+            .clone()
+            .with_periodicity_selection(PeriodicitySelection::Weekly)
+            .is_cacheable());
+        assert!(!options.with_max_points(10).is_cacheable());
+    }
 }
@@ -0,0 +1,684 @@
+use crate::{
+    batch::Batch,
+    bindings::*,
+    correlation_id::CorrelationId,
+    event::{Event, EventType},
+    identity::{self, Identity},
+    intraday::{self, Bar, BarOptions, Tick, TickOptions},
+    name,
+    overrides::Overrides,
+    ref_data::RefData,
+    request::Request,
+    service::Service,
+    session::{self, HistOptions, Session, TimeSerie},
+    session_options::SessionOptions,
+    subscription::SubscriptionList,
+    Error,
+};
+use futures::{
+    channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+    Stream, StreamExt,
+};
+use std::{
+    collections::HashMap,
+    ffi::{c_void, CString},
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+const DISPATCHER_THREADS: usize = 1;
+
+#[derive(Default)]
+struct Router {
+    requests: Mutex<HashMap<u64, UnboundedSender<Event>>>,
+    listeners: Mutex<Vec<UnboundedSender<Event>>>,
+}
+
+impl Router {
+    fn register(&self, correlation_id: &CorrelationId) -> UnboundedReceiver<Event> {
+        let (sender, receiver) = unbounded();
+
+        if let Some(id) = correlation_id.as_u64() {
+            self.requests.lock().unwrap().insert(id, sender);
+        }
+
+        receiver
+    }
+
+    fn register_all(&self, correlation_ids: &[CorrelationId]) -> UnboundedReceiver<Event> {
+        let (sender, receiver) = unbounded();
+
+        let mut requests = self.requests.lock().unwrap();
+
+        for id in correlation_ids.iter().filter_map(|id| id.as_u64()) {
+            requests.insert(id, sender.clone());
+        }
+
+        receiver
+    }
+
+    fn unregister(&self, correlation_id: &CorrelationId) {
+        if let Some(id) = correlation_id.as_u64() {
+            self.requests.lock().unwrap().remove(&id);
+        }
+    }
+
+    fn listen(&self) -> UnboundedReceiver<Event> {
+        let (sender, receiver) = unbounded();
+
+        self.listeners.lock().unwrap().push(sender);
+
+        receiver
+    }
+
+    fn route(&self, event: Event) {
+        let event_type: EventType = event.event_type();
+
+        let mut ids: Vec<(u64, bool)> = Vec::new();
+
+        for message in event.messages() {
+            let last: bool = match event_type {
+                EventType::Response | EventType::RequestStatus => true,
+                EventType::SubscriptionStatus => {
+                    let message_type = message.message_type();
+
+                    message_type == *name::SUBSCRIPTION_FAILURE
+                        || message_type == *name::SUBSCRIPTION_TERMINATED
+                }
+                _ => false,
+            };
+
+            for i in 0..message.num_correlation_ids() {
+                if let Some(id) = message.correlation_id(i).and_then(|id| id.as_u64()) {
+                    ids.push((id, last));
+                }
+            }
+        }
+
+        let mut routed: Vec<UnboundedSender<Event>> = Vec::new();
+
+        {
+            let mut requests = self.requests.lock().unwrap();
+
+            for (id, last) in ids {
+                let sender: Option<UnboundedSender<Event>> = if last {
+                    requests.remove(&id)
+                } else {
+                    requests.get(&id).cloned()
+                };
+
+                if let Some(sender) = sender {
+                    if !routed.iter().any(|s| s.same_receiver(&sender))
+                        && sender.unbounded_send(event.clone()).is_ok()
+                    {
+                        routed.push(sender);
+                    }
+                }
+            }
+
+            if event_type == EventType::SessionStatus
+                && event
+                    .messages()
+                    .any(|m| m.message_type() == *name::SESSION_TERMINATED)
+            {
+                requests.clear();
+            }
+        }
+
+        if routed.is_empty() {
+            self.listeners
+                .lock()
+                .unwrap()
+                .retain(|listener| listener.unbounded_send(event.clone()).is_ok());
+        }
+    }
+}
+
+unsafe extern "C" fn on_event(
+    event: *mut blpapi_Event,
+    _session: *mut blpapi_Session,
+    user_data: *mut c_void,
+) {
+    let router: &Router = &*(user_data as *const Router);
+
+    blpapi_Event_addRef(event);
+
+    let res = panic::catch_unwind(AssertUnwindSafe(|| router.route(Event(event))));
+
+    if res.is_err() {
+        log::error!("Panic while dispatching event...");
+    }
+}
+
+struct EventDispatcher(*mut blpapi_EventDispatcher_t);
+
+unsafe impl Send for EventDispatcher {}
+unsafe impl Sync for EventDispatcher {}
+
+impl EventDispatcher {
+    fn new(threads: usize) -> Result<Self, Error> {
+        unsafe {
+            let dispatcher: EventDispatcher =
+                EventDispatcher(blpapi_EventDispatcher_create(threads));
+
+            Error::check(blpapi_EventDispatcher_start(dispatcher.0))?;
+
+            Ok(dispatcher)
+        }
+    }
+}
+
+impl Drop for EventDispatcher {
+    fn drop(&mut self) {
+        unsafe {
+            blpapi_EventDispatcher_stop(self.0, 0);
+            blpapi_EventDispatcher_destroy(self.0);
+        }
+    }
+}
+
+pub struct SessionAsync {
+    session: Session,
+    router: Arc<Router>,
+    _dispatcher: EventDispatcher,
+}
+
+impl SessionAsync {
+    pub fn from_options(options: SessionOptions) -> Result<Self, Error> {
+        #[cfg(feature = "dynamic")]
+        crate::bindings::load()?;
+
+        crate::version::check()?;
+
+        let router: Arc<Router> = Arc::new(Router::default());
+        let dispatcher: EventDispatcher = EventDispatcher::new(DISPATCHER_THREADS)?;
+        let user_data: *mut c_void = Arc::as_ptr(&router) as *mut c_void;
+
+        let session: Session =
+            Session::with_handler(options, Some(on_event), dispatcher.0, user_data);
+
+        Ok(SessionAsync {
+            session,
+            router,
+            _dispatcher: dispatcher,
+        })
+    }
+
+    pub async fn new() -> Result<Self, Error> {
+        let session: SessionAsync = Self::from_options(SessionOptions::new()?)?;
+
+        session.start().await?;
+
+        session.open_service("//blp/refdata").await?;
+
+        Ok(session)
+    }
+
+    pub async fn start(&self) -> Result<(), Error> {
+        let mut events: EventStream = self.events();
+
+        Error::check(unsafe { blpapi_Session_startAsync(self.session.ptr) })?;
+
+        while let Some(event) = events.next().await {
+            if event.event_type() != EventType::SessionStatus {
+                continue;
+            }
+
+            for message in event.messages() {
+                let message_type = message.message_type();
+
+                if message_type == *name::SESSION_STARTED {
+                    return Ok(());
+                } else if message_type == *name::SESSION_STARTUP_FAILURE {
+                    return Err(Error::SessionStartupFailure);
+                } else if message_type == *name::SESSION_TERMINATED {
+                    return Err(Error::SessionTerminated);
+                }
+            }
+        }
+
+        Err(Error::SessionTerminated)
+    }
+
+    pub async fn stop(&self) -> Result<(), Error> {
+        let mut events: EventStream = self.events();
+
+        Error::check(unsafe { blpapi_Session_stopAsync(self.session.ptr) })?;
+
+        while let Some(event) = events.next().await {
+            if event.event_type() == EventType::SessionStatus
+                && event
+                    .messages()
+                    .any(|m| m.message_type() == *name::SESSION_TERMINATED)
+            {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn open_service(&self, service: &str) -> Result<(), Error> {
+        let mut correlation_id: CorrelationId = self.session.new_correlation_id();
+        let mut receiver: UnboundedReceiver<Event> = self.router.register(&correlation_id);
+        let name: CString = CString::new(service).unwrap();
+
+        let res: i32 = unsafe {
+            blpapi_Session_openServiceAsync(
+                self.session.ptr,
+                name.as_ptr(),
+                &mut correlation_id.0 as *mut _,
+            )
+        };
+
+        if let Err(e) = Error::check(res) {
+            self.router.unregister(&correlation_id);
+
+            return Err(e);
+        }
+
+        let opened: bool = match receiver.next().await {
+            Some(event) => event
+                .messages()
+                .any(|m| m.message_type() == *name::SERVICE_OPENED),
+            None => false,
+        };
+
+        self.router.unregister(&correlation_id);
+
+        if opened {
+            Ok(())
+        } else {
+            Err(Error::ServiceOpenFailure(service.to_owned()))
+        }
+    }
+
+    pub fn get_service(&self, service: &str) -> Result<Service, Error> {
+        self.session.get_service(service)
+    }
+
+    pub fn send(
+        &self,
+        request: Request,
+        correlation_id: Option<CorrelationId>,
+    ) -> Result<Response<'_>, Error> {
+        self.send_as(request, self.session.identity(), correlation_id)
+    }
+
+    pub fn send_as(
+        &self,
+        request: Request,
+        identity: Option<&Identity>,
+        correlation_id: Option<CorrelationId>,
+    ) -> Result<Response<'_>, Error> {
+        let correlation_id: CorrelationId =
+            correlation_id.unwrap_or_else(|| self.session.new_correlation_id());
+        let receiver: UnboundedReceiver<Event> = self.router.register(&correlation_id);
+
+        match self
+            .session
+            .send_as(request, identity, Some(correlation_id))
+        {
+            Ok(correlation_id) => Ok(Response {
+                session: self,
+                correlation_id,
+                receiver,
+                exit: false,
+            }),
+            Err(e) => {
+                self.router.unregister(&correlation_id);
+
+                Err(e)
+            }
+        }
+    }
+
+    pub fn subscribe(&self, subscriptions: &SubscriptionList) -> Result<SubscriptionStream, Error> {
+        self.subscribe_as(subscriptions, self.session.identity())
+    }
+
+    pub fn subscribe_as(
+        &self,
+        subscriptions: &SubscriptionList,
+        identity: Option<&Identity>,
+    ) -> Result<SubscriptionStream, Error> {
+        let correlation_ids: Vec<CorrelationId> = subscriptions.correlation_ids();
+        let receiver: UnboundedReceiver<Event> = self.router.register_all(&correlation_ids);
+
+        if let Err(e) = self.session.subscribe_as(subscriptions, identity) {
+            for correlation_id in correlation_ids.iter() {
+                self.router.unregister(correlation_id);
+            }
+
+            return Err(e);
+        }
+
+        Ok(SubscriptionStream {
+            router: self.router.clone(),
+            correlation_ids,
+            receiver,
+        })
+    }
+
+    pub fn set_identity(&mut self, identity: Option<Identity>) {
+        self.session.set_identity(identity);
+    }
+
+    pub async fn token(&self) -> Result<String, Error> {
+        let correlation_id: CorrelationId = self.session.new_correlation_id();
+        let mut receiver: UnboundedReceiver<Event> = self.router.register(&correlation_id);
+
+        if let Err(e) = self.session.generate_token(Some(correlation_id)) {
+            self.router.unregister(&correlation_id);
+
+            return Err(e);
+        }
+
+        let mut token: Result<String, Error> = Err(Error::SessionTerminated);
+
+        while let Some(event) = receiver.next().await {
+            if let Some(res) = identity::on_token(&event, &correlation_id) {
+                token = res;
+
+                break;
+            }
+        }
+
+        self.router.unregister(&correlation_id);
+
+        token
+    }
+
+    pub async fn authorize(&self) -> Result<Identity, Error> {
+        let token: String = self.token().await?;
+
+        self.authorize_token(&token).await
+    }
+
+    pub async fn authorize_token(&self, token: &str) -> Result<Identity, Error> {
+        let mut request: Request = self.authorization_request().await?;
+
+        request.append("token", token)?;
+
+        self.send_authorization(request).await
+    }
+
+    pub async fn authorize_user(&self, uuid: i32, ip_address: &str) -> Result<Identity, Error> {
+        let mut request: Request = self.authorization_request().await?;
+
+        request.append("uuid", uuid)?;
+        request.append("ipAddress", ip_address)?;
+
+        self.send_authorization(request).await
+    }
+
+    async fn authorization_request(&self) -> Result<Request, Error> {
+        self.open_service("//blp/apiauth").await?;
+
+        self.get_service("//blp/apiauth")?
+            .create_request("AuthorizationRequest")
+    }
+
+    async fn send_authorization(&self, request: Request) -> Result<Identity, Error> {
+        let identity: Identity = self.session.create_identity();
+        let correlation_id: CorrelationId = self.session.new_correlation_id();
+        let mut receiver: UnboundedReceiver<Event> = self.router.register(&correlation_id);
+
+        if let Err(e) =
+            self.session
+                .send_authorization_request(request, &identity, Some(correlation_id))
+        {
+            self.router.unregister(&correlation_id);
+
+            return Err(e);
+        }
+
+        let mut authorized: Result<(), Error> = Err(Error::SessionTerminated);
+
+        while let Some(event) = receiver.next().await {
+            if let Some(res) = identity::on_authorization(&event, &correlation_id) {
+                authorized = res;
+
+                break;
+            }
+        }
+
+        self.router.unregister(&correlation_id);
+
+        authorized.map(|_| identity)
+    }
+
+    pub fn events(&self) -> EventStream {
+        EventStream(self.router.listen())
+    }
+
+    pub async fn ref_data<I, R>(&self, securities: I) -> Result<Batch<HashMap<String, R>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: RefData,
+    {
+        self.ref_data_with_overrides(securities, &Overrides::default())
+            .await
+    }
+
+    pub async fn ref_data_with_overrides<I, R>(
+        &self,
+        securities: I,
+        overrides: &Overrides,
+    ) -> Result<Batch<HashMap<String, R>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: RefData,
+    {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = session::collect_securities(securities);
+        let overrides: Overrides = Overrides::from(R::OVERRIDES).merge(overrides);
+        let mut ref_data: Batch<HashMap<String, R>> = Batch::default();
+
+        let requests: Vec<Request> =
+            session::ref_data_requests(&service, &securities, R::FIELDS, &overrides)
+                .collect::<Result<_, _>>()?;
+
+        for request in requests {
+            let mut response: Response = self.send(request, None)?;
+
+            while let Some(event) = response.next().await {
+                session::on_ref_data(&event?, &mut ref_data)?;
+            }
+        }
+
+        Ok(ref_data)
+    }
+
+    pub async fn hist_data<I, R>(
+        &self,
+        securities: I,
+        options: HistOptions,
+    ) -> Result<Batch<HashMap<String, TimeSerie<R>>>, Error>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        R: RefData,
+    {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let securities: Vec<String> = session::collect_securities(securities);
+        let overrides: Overrides = Overrides::from(R::OVERRIDES).merge(options.overrides());
+        let mut hist_data: Batch<HashMap<String, TimeSerie<R>>> = Batch::default();
+
+        let requests: Vec<Request> =
+            session::hist_data_requests(&service, &securities, R::FIELDS, &options, &overrides)
+                .collect::<Result<_, _>>()?;
+
+        for request in requests {
+            let mut response: Response = self.send(request, None)?;
+
+            while let Some(event) = response.next().await {
+                session::on_hist_data(&event?, &mut hist_data)?;
+            }
+        }
+
+        Ok(hist_data)
+    }
+
+    pub async fn bar_data(&self, security: &str, options: BarOptions) -> Result<Vec<Bar>, Error> {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let mut bars: Vec<Bar> = Vec::new();
+
+        let requests: Vec<Request> =
+            intraday::bar_data_requests(&service, security, &options).collect::<Result<_, _>>()?;
+
+        for request in requests {
+            let mut response: Response = self.send(request, None)?;
+
+            while let Some(event) = response.next().await {
+                intraday::on_bar_data(&event?, security, &mut bars)?;
+            }
+        }
+
+        intraday::sort_bars(&mut bars);
+
+        Ok(bars)
+    }
+
+    pub async fn tick_data(
+        &self,
+        security: &str,
+        options: TickOptions,
+    ) -> Result<Vec<Tick>, Error> {
+        let service: Service = self.get_service("//blp/refdata")?;
+        let mut ticks: Vec<Tick> = Vec::new();
+
+        let requests: Vec<Request> =
+            intraday::tick_data_requests(&service, security, &options).collect::<Result<_, _>>()?;
+
+        for request in requests {
+            let mut response: Response = self.send(request, None)?;
+
+            while let Some(event) = response.next().await {
+                intraday::on_tick_data(&event?, security, &mut ticks)?;
+            }
+        }
+
+        Ok(ticks)
+    }
+}
+
+impl std::ops::Deref for SessionAsync {
+    type Target = Session;
+
+    fn deref(&self) -> &Session {
+        &self.session
+    }
+}
+
+pub struct Response<'a> {
+    session: &'a SessionAsync,
+    correlation_id: CorrelationId,
+    receiver: UnboundedReceiver<Event>,
+    exit: bool,
+}
+
+impl<'a> Response<'a> {
+    pub fn correlation_id(&self) -> CorrelationId {
+        self.correlation_id
+    }
+}
+
+impl<'a> Stream for Response<'a> {
+    type Item = Result<Event, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.exit {
+                return Poll::Ready(None);
+            }
+
+            let event: Event = match self.receiver.poll_next_unpin(cx) {
+                Poll::Ready(Some(event)) => event,
+                Poll::Ready(None) => {
+                    self.exit = true;
+
+                    return Poll::Ready(Some(Err(Error::SessionTerminated)));
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match event.event_type() {
+                EventType::PartialResponse => return Poll::Ready(Some(Ok(event))),
+                EventType::Response => {
+                    self.exit = true;
+
+                    return Poll::Ready(Some(Ok(event)));
+                }
+                EventType::RequestStatus => {
+                    self.exit = true;
+
+                    let failure: Option<String> = event
+                        .messages()
+                        .find(|m| m.message_type() == *name::REQUEST_FAILURE)
+                        .map(|m| m.failure_reason());
+
+                    return Poll::Ready(failure.map(|reason| Err(Error::RequestFailure(reason))));
+                }
+                EventType::Timeout => return Poll::Ready(Some(Err(Error::TimeOut))),
+                _ => (),
+            }
+        }
+    }
+}
+
+impl<'a> Drop for Response<'a> {
+    fn drop(&mut self) {
+        self.session.router.unregister(&self.correlation_id);
+
+        if self.exit {
+            return;
+        }
+
+        if let Err(e) = self.session.session.cancel(&[self.correlation_id]) {
+            log::debug!("Failed to cancel request: {}...", e);
+        }
+    }
+}
+
+pub struct EventStream(UnboundedReceiver<Event>);
+
+impl Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        self.0.poll_next_unpin(cx)
+    }
+}
+
+pub struct SubscriptionStream {
+    router: Arc<Router>,
+    correlation_ids: Vec<CorrelationId>,
+    receiver: UnboundedReceiver<Event>,
+}
+
+impl SubscriptionStream {
+    pub fn correlation_ids(&self) -> &[CorrelationId] {
+        &self.correlation_ids
+    }
+}
+
+impl Stream for SubscriptionStream {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for SubscriptionStream {
+    fn drop(&mut self) {
+        for correlation_id in self.correlation_ids.iter() {
+            self.router.unregister(correlation_id);
+        }
+    }
+}
@@ -1,11 +1,26 @@
 use crate::bindings::*;
-use crate::{session::SessionSync, Error};
+use crate::{
+    identity::Authentication,
+    schema::{print, string},
+    session::SessionSync,
+    tls_options::TlsOptions,
+    Error,
+};
 use std::ffi::{CStr, CString};
-use std::os::raw::c_int;
+use std::os::raw::{c_char, c_int, c_ushort};
+use std::ptr;
+use std::time::Duration;
 
 pub struct SessionOptions(pub(crate) *mut blpapi_SessionOptions_t);
 
 impl SessionOptions {
+    pub fn new() -> Result<Self, Error> {
+        #[cfg(feature = "dynamic")]
+        crate::bindings::load()?;
+
+        Ok(SessionOptions::default())
+    }
+
     pub fn client_mode(&self) -> Result<ClientMode, Error> {
         let mode: i32 = unsafe { blpapi_SessionOptions_clientMode(self.0) };
 
@@ -16,7 +31,7 @@ impl SessionOptions {
             BLPAPI_CLIENTMODE_DAPI => Ok(ClientMode::DApi),
             BLPAPI_CLIENTMODE_SAPI => Ok(ClientMode::SApi),
             BLPAPI_CLIENTMODE_COMPAT_33X => Ok(ClientMode::Compat33X),
-            _ => Err(Error::Generic(mode)),
+            _ => Err(Error::Conversion(format!("unknown client mode {}", mode))),
         }
     }
 
@@ -48,6 +63,12 @@ impl SessionOptions {
         Ok(self)
     }
 
+    pub fn with_client_mode(mut self, mode: ClientMode) -> Self {
+        self.set_client_mode(mode);
+
+        self
+    }
+
     pub fn server_port(&self) -> u16 {
         unsafe { blpapi_SessionOptions_serverPort(self.0) as u16 }
     }
@@ -60,11 +81,338 @@ impl SessionOptions {
         Ok(self)
     }
 
+    pub fn num_server_addresses(&self) -> usize {
+        unsafe { blpapi_SessionOptions_numServerAddresses(self.0) as usize }
+    }
+
+    pub fn server_address(&self, index: usize) -> Result<(String, u16), Error> {
+        let mut host: *const c_char = ptr::null();
+        let mut port: c_ushort = 0;
+
+        let res: i32 =
+            unsafe { blpapi_SessionOptions_getServerAddress(self.0, &mut host, &mut port, index) };
+
+        Error::check(res)?;
+
+        Ok((unsafe { string(host) }, port))
+    }
+
+    pub fn server_addresses(&self) -> Vec<(String, u16)> {
+        (0..self.num_server_addresses())
+            .filter_map(|index| self.server_address(index).ok())
+            .collect()
+    }
+
+    pub fn with_server_address(self, host: &str, port: u16, index: usize) -> Result<Self, Error> {
+        let chost: CString = CString::new(host).unwrap();
+        let res: i32 =
+            unsafe { blpapi_SessionOptions_setServerAddress(self.0, chost.as_ptr(), port, index) };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn remove_server_address(&mut self, index: usize) -> Result<(), Error> {
+        let res: i32 = unsafe { blpapi_SessionOptions_removeServerAddress(self.0, index) };
+
+        Error::check(res)
+    }
+
+    pub fn connect_timeout(&self) -> Duration {
+        let timeout: u32 = unsafe { blpapi_SessionOptions_connectTimeout(self.0) };
+
+        Duration::from_millis(timeout as u64)
+    }
+
+    pub fn with_connect_timeout(self, timeout: Duration) -> Result<Self, Error> {
+        let res: i32 =
+            unsafe { blpapi_SessionOptions_setConnectTimeout(self.0, timeout.as_millis() as u32) };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn auto_restart_on_disconnection(&self) -> bool {
+        unsafe { blpapi_SessionOptions_autoRestartOnDisconnection(self.0) != 0 }
+    }
+
+    pub fn with_auto_restart_on_disconnection(self, auto_restart: bool) -> Self {
+        unsafe {
+            blpapi_SessionOptions_setAutoRestartOnDisconnection(self.0, auto_restart as c_int)
+        };
+
+        self
+    }
+
+    pub fn num_start_attempts(&self) -> usize {
+        unsafe { blpapi_SessionOptions_numStartAttempts(self.0) as usize }
+    }
+
+    pub fn with_num_start_attempts(self, attempts: usize) -> Self {
+        unsafe { blpapi_SessionOptions_setNumStartAttempts(self.0, attempts as c_int) };
+
+        self
+    }
+
+    pub fn authentication_options(&self) -> String {
+        unsafe { string(blpapi_SessionOptions_authenticationOptions(self.0)) }
+    }
+
+    pub fn with_authentication_options(self, options: &str) -> Self {
+        let options: CString = CString::new(options).unwrap();
+
+        unsafe { blpapi_SessionOptions_setAuthenticationOptions(self.0, options.as_ptr()) };
+
+        self
+    }
+
+    pub fn with_authentication(self, authentication: &Authentication) -> Self {
+        self.with_authentication_options(&authentication.to_string())
+    }
+
+    pub fn default_services(&self) -> String {
+        unsafe { string(blpapi_SessionOptions_defaultServices(self.0)) }
+    }
+
+    pub fn with_default_services(self, services: &str) -> Result<Self, Error> {
+        let services: CString = CString::new(services).unwrap();
+        let res: i32 =
+            unsafe { blpapi_SessionOptions_setDefaultServices(self.0, services.as_ptr()) };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn default_subscription_service(&self) -> String {
+        unsafe { string(blpapi_SessionOptions_defaultSubscriptionService(self.0)) }
+    }
+
+    pub fn with_default_subscription_service(self, service: &str) -> Result<Self, Error> {
+        let service: CString = CString::new(service).unwrap();
+        let res: i32 = unsafe {
+            blpapi_SessionOptions_setDefaultSubscriptionService(self.0, service.as_ptr())
+        };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn default_topic_prefix(&self) -> String {
+        unsafe { string(blpapi_SessionOptions_defaultTopicPrefix(self.0)) }
+    }
+
+    pub fn with_default_topic_prefix(self, prefix: &str) -> Self {
+        let prefix: CString = CString::new(prefix).unwrap();
+
+        unsafe { blpapi_SessionOptions_setDefaultTopicPrefix(self.0, prefix.as_ptr()) };
+
+        self
+    }
+
+    pub fn allow_multiple_correlators_per_msg(&self) -> bool {
+        unsafe { blpapi_SessionOptions_allowMultipleCorrelatorsPerMsg(self.0) != 0 }
+    }
+
+    pub fn with_allow_multiple_correlators_per_msg(self, allow: bool) -> Self {
+        unsafe { blpapi_SessionOptions_setAllowMultipleCorrelatorsPerMsg(self.0, allow as c_int) };
+
+        self
+    }
+
+    pub fn max_pending_requests(&self) -> usize {
+        unsafe { blpapi_SessionOptions_maxPendingRequests(self.0) as usize }
+    }
+
+    pub fn with_max_pending_requests(self, max_pending_requests: usize) -> Self {
+        unsafe {
+            blpapi_SessionOptions_setMaxPendingRequests(self.0, max_pending_requests as c_int)
+        };
+
+        self
+    }
+
+    pub fn max_event_queue_size(&self) -> usize {
+        unsafe { blpapi_SessionOptions_maxEventQueueSize(self.0) }
+    }
+
+    pub fn with_max_event_queue_size(self, size: usize) -> Self {
+        unsafe { blpapi_SessionOptions_setMaxEventQueueSize(self.0, size) };
+
+        self
+    }
+
+    pub fn slow_consumer_warning_hi_water_mark(&self) -> f32 {
+        unsafe { blpapi_SessionOptions_slowConsumerWarningHiWaterMark(self.0) }
+    }
+
+    pub fn with_slow_consumer_warning_hi_water_mark(self, mark: f32) -> Result<Self, Error> {
+        let res: i32 =
+            unsafe { blpapi_SessionOptions_setSlowConsumerWarningHiWaterMark(self.0, mark) };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn slow_consumer_warning_lo_water_mark(&self) -> f32 {
+        unsafe { blpapi_SessionOptions_slowConsumerWarningLoWaterMark(self.0) }
+    }
+
+    pub fn with_slow_consumer_warning_lo_water_mark(self, mark: f32) -> Result<Self, Error> {
+        let res: i32 =
+            unsafe { blpapi_SessionOptions_setSlowConsumerWarningLoWaterMark(self.0, mark) };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn keep_alive_enabled(&self) -> bool {
+        unsafe { blpapi_SessionOptions_keepAliveEnabled(self.0) != 0 }
+    }
+
+    pub fn with_keep_alive_enabled(self, enabled: bool) -> Result<Self, Error> {
+        let res: i32 =
+            unsafe { blpapi_SessionOptions_setKeepAliveEnabled(self.0, enabled as c_int) };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn default_keep_alive_inactivity_time(&self) -> Duration {
+        millis(unsafe { blpapi_SessionOptions_defaultKeepAliveInactivityTime(self.0) })
+    }
+
+    pub fn with_default_keep_alive_inactivity_time(self, time: Duration) -> Result<Self, Error> {
+        let res: i32 = unsafe {
+            blpapi_SessionOptions_setDefaultKeepAliveInactivityTime(
+                self.0,
+                time.as_millis() as c_int,
+            )
+        };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn default_keep_alive_response_timeout(&self) -> Duration {
+        millis(unsafe { blpapi_SessionOptions_defaultKeepAliveResponseTimeout(self.0) })
+    }
+
+    pub fn with_default_keep_alive_response_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<Self, Error> {
+        let res: i32 = unsafe {
+            blpapi_SessionOptions_setDefaultKeepAliveResponseTimeout(
+                self.0,
+                timeout.as_millis() as c_int,
+            )
+        };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn record_subscription_data_receive_times(&self) -> bool {
+        unsafe { blpapi_SessionOptions_recordSubscriptionDataReceiveTimes(self.0) != 0 }
+    }
+
+    pub fn with_record_subscription_data_receive_times(self, record: bool) -> Self {
+        unsafe {
+            blpapi_SessionOptions_setRecordSubscriptionDataReceiveTimes(self.0, record as c_int)
+        };
+
+        self
+    }
+
+    pub fn service_check_timeout(&self) -> Duration {
+        millis(unsafe { blpapi_SessionOptions_serviceCheckTimeout(self.0) })
+    }
+
+    pub fn with_service_check_timeout(self, timeout: Duration) -> Result<Self, Error> {
+        let res: i32 = unsafe {
+            blpapi_SessionOptions_setServiceCheckTimeout(self.0, timeout.as_millis() as c_int)
+        };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn service_download_timeout(&self) -> Duration {
+        millis(unsafe { blpapi_SessionOptions_serviceDownloadTimeout(self.0) })
+    }
+
+    pub fn with_service_download_timeout(self, timeout: Duration) -> Result<Self, Error> {
+        let res: i32 = unsafe {
+            blpapi_SessionOptions_setServiceDownloadTimeout(self.0, timeout.as_millis() as c_int)
+        };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn flush_published_events_timeout(&self) -> Duration {
+        millis(unsafe { blpapi_SessionOptions_flushPublishedEventsTimeout(self.0) })
+    }
+
+    pub fn with_flush_published_events_timeout(self, timeout: Duration) -> Result<Self, Error> {
+        let res: i32 = unsafe {
+            blpapi_SessionOptions_setFlushPublishedEventsTimeout(
+                self.0,
+                timeout.as_millis() as c_int,
+            )
+        };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn bandwidth_save_mode_disabled(&self) -> bool {
+        unsafe { blpapi_SessionOptions_bandwidthSaveModeDisabled(self.0) != 0 }
+    }
+
+    pub fn with_bandwidth_save_mode_disabled(self, disabled: bool) -> Result<Self, Error> {
+        let res: i32 = unsafe {
+            blpapi_SessionOptions_setBandwidthSaveModeDisabled(self.0, disabled as c_int)
+        };
+
+        Error::check(res)?;
+
+        Ok(self)
+    }
+
+    pub fn with_tls_options(self, tls_options: &TlsOptions) -> Self {
+        unsafe { blpapi_SessionOptions_setTlsOptions(self.0, tls_options.0) };
+
+        self
+    }
+
     pub fn sync(self) -> SessionSync {
         SessionSync::from_options(self)
     }
 }
 
+impl std::fmt::Debug for SessionOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        let options: String = print(|writer, stream| unsafe {
+            blpapi_SessionOptions_print(self.0, writer, stream, 0, -1)
+        });
+
+        write!(f, "SessionOptions: '{}'...", options.trim())
+    }
+}
+
 impl Drop for SessionOptions {
     fn drop(&mut self) {
         unsafe { blpapi_SessionOptions_destroy(self.0) }
@@ -97,11 +445,16 @@ pub enum ClientMode {
     Compat33X,
 }
 
+fn millis(ms: c_int) -> Duration {
+    Duration::from_millis(ms.max(0) as u64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
     fn test_server_host() {
         let host: &str = "localhost";
         let options: SessionOptions = SessionOptions::default().with_server_host(host).unwrap();
@@ -110,4 +463,32 @@ mod tests {
 
         let _session: SessionSync = options.sync();
     }
+
+    #[test]
+    #[cfg_attr(feature = "dynamic", ignore = "requires the blpapi SDK")]
+    fn test_options() {
+        let options: SessionOptions = SessionOptions::default()
+            .with_server_address("primary", 8194, 0)
+            .unwrap()
+            .with_server_address("secondary", 8196, 1)
+            .unwrap()
+            .with_connect_timeout(Duration::from_secs(10))
+            .unwrap()
+            .with_auto_restart_on_disconnection(true)
+            .with_max_event_queue_size(20_000)
+            .with_record_subscription_data_receive_times(true)
+            .with_service_check_timeout(Duration::from_secs(5))
+            .unwrap();
+
+        assert_eq!(
+            options.server_addresses(),
+            vec![("primary".to_owned(), 8194), ("secondary".to_owned(), 8196)]
+        );
+
+        assert_eq!(options.connect_timeout(), Duration::from_secs(10));
+        assert!(options.auto_restart_on_disconnection());
+        assert_eq!(options.max_event_queue_size(), 20_000);
+        assert!(options.record_subscription_data_receive_times());
+        assert_eq!(options.service_check_timeout(), Duration::from_secs(5));
+    }
 }
@@ -0,0 +1,304 @@
+use crate::bindings::*;
+use crate::{
+    correlation_id::CorrelationId,
+    element::Element,
+    event::{Event, EventType},
+    message::Message,
+    name, Error,
+};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+
+#[derive(Debug, Clone, Default)]
+pub struct Subscription {
+    topic: String,
+    fields: Vec<String>,
+    options: Vec<(String, Option<String>)>,
+}
+
+impl Subscription {
+    pub fn new<S: Into<String>>(topic: S) -> Self {
+        Subscription {
+            topic: topic.into(),
+            ..Subscription::default()
+        }
+    }
+
+    pub fn with_field<S: Into<String>>(mut self, field: S) -> Self {
+        self.fields.push(field.into());
+        self
+    }
+
+    pub fn with_fields<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_option<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.options.push((key.into(), Some(value.into())));
+        self
+    }
+
+    pub fn with_flag<K: Into<String>>(mut self, key: K) -> Self {
+        self.options.push((key.into(), None));
+        self
+    }
+
+    pub fn with_interval(self, seconds: f64) -> Self {
+        self.with_option("interval", format!("{:.1}", seconds))
+    }
+
+    pub fn with_delayed(self) -> Self {
+        self.with_flag("delayed")
+    }
+
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    pub fn options(&self) -> Vec<String> {
+        self.options
+            .iter()
+            .map(|(key, value)| match value {
+                Some(value) => format!("{}={}", key, value),
+                None => key.clone(),
+            })
+            .collect()
+    }
+}
+
+pub struct SubscriptionList(pub(crate) *mut blpapi_SubscriptionList_t);
+
+unsafe impl Send for SubscriptionList {}
+
+impl SubscriptionList {
+    pub fn new() -> Self {
+        unsafe { SubscriptionList(blpapi_SubscriptionList_create()) }
+    }
+
+    pub fn add(
+        &mut self,
+        subscription: &Subscription,
+        correlation_id: CorrelationId,
+    ) -> Result<(), Error> {
+        let topic: CString = CString::new(&subscription.topic[..]).unwrap();
+
+        let fields: Vec<CString> = subscription
+            .fields
+            .iter()
+            .map(|f| CString::new(&f[..]).unwrap())
+            .collect();
+
+        let options: Vec<CString> = subscription
+            .options()
+            .into_iter()
+            .map(|o| CString::new(o).unwrap())
+            .collect();
+
+        let mut field_ptrs: Vec<*const c_char> = fields.iter().map(|f| f.as_ptr()).collect();
+        let mut option_ptrs: Vec<*const c_char> = options.iter().map(|o| o.as_ptr()).collect();
+
+        unsafe {
+            let res: i32 = blpapi_SubscriptionList_add(
+                self.0,
+                topic.as_ptr(),
+                &correlation_id.0 as *const _,
+                field_ptrs.as_mut_ptr(),
+                option_ptrs.as_mut_ptr(),
+                field_ptrs.len(),
+                option_ptrs.len(),
+            );
+
+            Error::check(res)
+        }
+    }
+
+    pub fn with(
+        mut self,
+        subscription: &Subscription,
+        correlation_id: CorrelationId,
+    ) -> Result<Self, Error> {
+        self.add(subscription, correlation_id)?;
+
+        Ok(self)
+    }
+
+    pub fn append(&mut self, other: &SubscriptionList) -> Result<(), Error> {
+        let res: i32 = unsafe { blpapi_SubscriptionList_append(self.0, other.0) };
+
+        Error::check(res)
+    }
+
+    pub fn clear(&mut self) -> Result<(), Error> {
+        let res: i32 = unsafe { blpapi_SubscriptionList_clear(self.0) };
+
+        Error::check(res)
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { blpapi_SubscriptionList_size(self.0) as usize }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn correlation_id_at(&self, index: usize) -> Option<CorrelationId> {
+        unsafe {
+            let mut id: CorrelationId = CorrelationId::new_u64(0);
+
+            let res: i32 =
+                blpapi_SubscriptionList_correlationIdAt(self.0, &mut id.0 as *mut _, index);
+
+            if res == 0 {
+                Some(id)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn topic_string_at(&self, index: usize) -> Option<String> {
+        unsafe {
+            let mut topic: *const c_char = ptr::null();
+
+            let res: i32 =
+                blpapi_SubscriptionList_topicStringAt(self.0, &mut topic as *mut _, index);
+
+            if res == 0 {
+                Some(CStr::from_ptr(topic).to_string_lossy().into_owned())
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn correlation_ids(&self) -> Vec<CorrelationId> {
+        (0..self.len())
+            .filter_map(|i| self.correlation_id_at(i))
+            .collect()
+    }
+}
+
+impl Default for SubscriptionList {
+    fn default() -> Self {
+        SubscriptionList::new()
+    }
+}
+
+impl Drop for SubscriptionList {
+    fn drop(&mut self) {
+        unsafe { blpapi_SubscriptionList_destroy(self.0) }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubscriptionError {
+    pub source: Option<String>,
+    pub category: Option<String>,
+    pub error_code: Option<i32>,
+    pub description: Option<String>,
+}
+
+impl SubscriptionError {
+    fn from_element(element: Option<Element>) -> Self {
+        let reason: Option<Element> = element.and_then(|e| e.get_named_element(&name::REASON));
+
+        SubscriptionError {
+            source: reason.as_ref().and_then(|r| r.element_value("source")),
+            category: reason.as_ref().and_then(|r| r.element_value("category")),
+            error_code: reason.as_ref().and_then(|r| r.element_value("errorCode")),
+            description: reason.as_ref().and_then(|r| r.element_value("description")),
+        }
+    }
+}
+
+pub enum SubscriptionMessage<'a> {
+    Data(CorrelationId, Message<'a>),
+    Started(CorrelationId),
+    Failure(CorrelationId, SubscriptionError),
+    Terminated(CorrelationId, SubscriptionError),
+    Status(CorrelationId, Message<'a>),
+}
+
+impl<'a> SubscriptionMessage<'a> {
+    pub fn correlation_id(&self) -> CorrelationId {
+        match self {
+            SubscriptionMessage::Data(id, _)
+            | SubscriptionMessage::Started(id)
+            | SubscriptionMessage::Failure(id, _)
+            | SubscriptionMessage::Terminated(id, _)
+            | SubscriptionMessage::Status(id, _) => *id,
+        }
+    }
+
+    fn from_message(
+        event_type: EventType,
+        correlation_id: CorrelationId,
+        message: Message<'a>,
+    ) -> Option<Self> {
+        match event_type {
+            EventType::SubscriptionData => Some(SubscriptionMessage::Data(correlation_id, message)),
+            EventType::SubscriptionStatus => {
+                let message_type = message.message_type();
+
+                if message_type == *name::SUBSCRIPTION_STARTED {
+                    Some(SubscriptionMessage::Started(correlation_id))
+                } else if message_type == *name::SUBSCRIPTION_FAILURE {
+                    let error = SubscriptionError::from_element(Some(message.element()));
+
+                    Some(SubscriptionMessage::Failure(correlation_id, error))
+                } else if message_type == *name::SUBSCRIPTION_TERMINATED {
+                    let error = SubscriptionError::from_element(Some(message.element()));
+
+                    Some(SubscriptionMessage::Terminated(correlation_id, error))
+                } else {
+                    Some(SubscriptionMessage::Status(correlation_id, message))
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Event {
+    pub fn subscription_messages(&self) -> impl Iterator<Item = SubscriptionMessage<'_>> {
+        let event_type: EventType = self.event_type();
+
+        self.messages().flat_map(move |message| {
+            let ids: Vec<CorrelationId> = (0..message.num_correlation_ids())
+                .filter_map(|i| message.correlation_id(i))
+                .collect();
+
+            ids.into_iter().filter_map(move |id| {
+                SubscriptionMessage::from_message(event_type, id, message.clone())
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscription_options() {
+        let subscription: Subscription = Subscription::new("IBM US Equity")
+            .with_fields(["LAST_PRICE", "BID", "ASK"])
+            .with_interval(5.)
+            .with_delayed();
+
+        assert_eq!(subscription.topic(), "IBM US Equity");
+        assert_eq!(subscription.fields(), &["LAST_PRICE", "BID", "ASK"]);
+        assert_eq!(subscription.options(), ["interval=5.0", "delayed"]);
+    }
+}
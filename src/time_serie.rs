@@ -0,0 +1,276 @@
+use chrono::NaiveDate;
+use std::ops::{Bound, RangeBounds};
+
+#[derive(Debug)]
+pub struct TimeSerie<R> {
+    pub values: Vec<R>,
+    dates: Vec<NaiveDate>,
+}
+
+impl<R> Default for TimeSerie<R> {
+    fn default() -> Self {
+        TimeSerie {
+            dates: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<R> TimeSerie<R> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        TimeSerie {
+            dates: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    pub fn values(&self) -> &[R] {
+        &self.values
+    }
+
+    pub fn first(&self) -> Option<(NaiveDate, &R)> {
+        Some((*self.dates.first()?, self.values.first()?))
+    }
+
+    pub fn last(&self) -> Option<(NaiveDate, &R)> {
+        Some((*self.dates.last()?, self.values.last()?))
+    }
+
+    pub fn get(&self, date: NaiveDate) -> Option<&R> {
+        let index: usize = self.dates.binary_search(&date).ok()?;
+
+        self.values.get(index)
+    }
+
+    pub fn get_mut(&mut self, date: NaiveDate) -> Option<&mut R> {
+        let index: usize = self.dates.binary_search(&date).ok()?;
+
+        self.values.get_mut(index)
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.dates.binary_search(&date).is_ok()
+    }
+
+    pub fn insert(&mut self, date: NaiveDate, value: R) -> Option<R> {
+        match self.dates.binary_search(&date) {
+            Ok(index) => Some(std::mem::replace(&mut self.values[index], value)),
+            Err(index) => {
+                self.dates.insert(index, date);
+                self.values.insert(index, value);
+
+                None
+            }
+        }
+    }
+
+    pub fn entry(&mut self, date: NaiveDate) -> &mut R
+    where
+        R: Default,
+    {
+        let index: usize = match self.dates.binary_search(&date) {
+            Ok(index) => index,
+            Err(index) => {
+                self.dates.insert(index, date);
+                self.values.insert(index, R::default());
+
+                index
+            }
+        };
+
+        &mut self.values[index]
+    }
+
+    pub fn iter(&self) -> Iter<'_, R> {
+        Iter {
+            dates: self.dates.iter(),
+            values: self.values.iter(),
+        }
+    }
+
+    pub fn range<B: RangeBounds<NaiveDate>>(&self, range: B) -> TimeSlice<'_, R> {
+        let start: usize = match range.start_bound() {
+            Bound::Included(date) => self.dates.partition_point(|d| d < date),
+            Bound::Excluded(date) => self.dates.partition_point(|d| d <= date),
+            Bound::Unbounded => 0,
+        };
+
+        let end: usize = match range.end_bound() {
+            Bound::Included(date) => self.dates.partition_point(|d| d <= date),
+            Bound::Excluded(date) => self.dates.partition_point(|d| d < date),
+            Bound::Unbounded => self.dates.len(),
+        };
+
+        let end: usize = end.max(start);
+
+        TimeSlice {
+            dates: &self.dates[start..end],
+            values: &self.values[start..end],
+        }
+    }
+
+    pub fn as_slice(&self) -> TimeSlice<'_, R> {
+        TimeSlice {
+            dates: &self.dates,
+            values: &self.values,
+        }
+    }
+}
+
+impl<R> FromIterator<(NaiveDate, R)> for TimeSerie<R> {
+    fn from_iter<I: IntoIterator<Item = (NaiveDate, R)>>(iter: I) -> Self {
+        let mut serie: TimeSerie<R> = TimeSerie::default();
+
+        for (date, value) in iter {
+            serie.insert(date, value);
+        }
+
+        serie
+    }
+}
+
+impl<'a, R> IntoIterator for &'a TimeSerie<R> {
+    type Item = (NaiveDate, &'a R);
+    type IntoIter = Iter<'a, R>;
+
+    fn into_iter(self) -> Iter<'a, R> {
+        self.iter()
+    }
+}
+
+impl<R> IntoIterator for TimeSerie<R> {
+    type Item = (NaiveDate, R);
+    type IntoIter = std::iter::Zip<std::vec::IntoIter<NaiveDate>, std::vec::IntoIter<R>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.dates.into_iter().zip(self.values)
+    }
+}
+
+#[derive(Debug)]
+pub struct TimeSlice<'a, R> {
+    dates: &'a [NaiveDate],
+    values: &'a [R],
+}
+
+impl<'a, R> TimeSlice<'a, R> {
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+
+    pub fn dates(&self) -> &'a [NaiveDate] {
+        self.dates
+    }
+
+    pub fn values(&self) -> &'a [R] {
+        self.values
+    }
+
+    pub fn get(&self, date: NaiveDate) -> Option<&'a R> {
+        let index: usize = self.dates.binary_search(&date).ok()?;
+
+        self.values.get(index)
+    }
+
+    pub fn iter(&self) -> Iter<'a, R> {
+        Iter {
+            dates: self.dates.iter(),
+            values: self.values.iter(),
+        }
+    }
+}
+
+impl<'a, R> Clone for TimeSlice<'a, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, R> Copy for TimeSlice<'a, R> {}
+
+impl<'a, R> IntoIterator for TimeSlice<'a, R> {
+    type Item = (NaiveDate, &'a R);
+    type IntoIter = Iter<'a, R>;
+
+    fn into_iter(self) -> Iter<'a, R> {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, R> {
+    dates: std::slice::Iter<'a, NaiveDate>,
+    values: std::slice::Iter<'a, R>,
+}
+
+impl<'a, R> Iterator for Iter<'a, R> {
+    type Item = (NaiveDate, &'a R);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.dates.size_hint()
+    }
+
+    fn next(&mut self) -> Option<(NaiveDate, &'a R)> {
+        Some((*self.dates.next()?, self.values.next()?))
+    }
+}
+
+impl<'a, R> DoubleEndedIterator for Iter<'a, R> {
+    fn next_back(&mut self) -> Option<(NaiveDate, &'a R)> {
+        Some((*self.dates.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<'a, R> ExactSizeIterator for Iter<'a, R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 4, day).unwrap()
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let serie: TimeSerie<i32> = vec![(date(3), 3), (date(1), 1), (date(2), 2)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(serie.dates(), &[date(1), date(2), date(3)]);
+        assert_eq!(serie.values(), &[1, 2, 3]);
+        assert_eq!(serie.get(date(2)), Some(&2));
+        assert_eq!(serie.get(date(4)), None);
+    }
+
+    #[test]
+    fn range() {
+        let serie: TimeSerie<u32> = (1..=10).map(|d| (date(d), d)).collect();
+
+        let values: Vec<u32> = serie
+            .range(date(3)..date(6))
+            .iter()
+            .map(|(_, v)| *v)
+            .collect();
+        assert_eq!(values, [3, 4, 5]);
+
+        assert_eq!(serie.range(date(8)..).len(), 3);
+        assert_eq!(serie.range(..=date(2)).len(), 2);
+        assert!(serie.range(date(6)..date(3)).is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::bindings::*;
+use crate::Error;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::path::Path;
+use std::time::Duration;
+
+pub struct TlsOptions(pub(crate) *mut blpapi_TlsOptions_t);
+
+unsafe impl Send for TlsOptions {}
+unsafe impl Sync for TlsOptions {}
+
+impl TlsOptions {
+    pub fn from_files<C: AsRef<Path>, T: AsRef<Path>>(
+        client_credentials: C,
+        password: &str,
+        trusted_certificates: T,
+    ) -> Result<Self, Error> {
+        let client_credentials: CString = path(client_credentials.as_ref())?;
+        let trusted_certificates: CString = path(trusted_certificates.as_ref())?;
+        let password: CString = CString::new(password).unwrap();
+
+        let ptr: *mut blpapi_TlsOptions_t = unsafe {
+            blpapi_TlsOptions_createFromFiles(
+                client_credentials.as_ptr(),
+                password.as_ptr(),
+                trusted_certificates.as_ptr(),
+            )
+        };
+
+        TlsOptions::from_ptr(ptr)
+    }
+
+    pub fn from_blobs(
+        client_credentials: &[u8],
+        password: &str,
+        trusted_certificates: &[u8],
+    ) -> Result<Self, Error> {
+        let password: CString = CString::new(password).unwrap();
+
+        let ptr: *mut blpapi_TlsOptions_t = unsafe {
+            blpapi_TlsOptions_createFromBlobs(
+                client_credentials.as_ptr() as *const c_char,
+                client_credentials.len() as c_int,
+                password.as_ptr(),
+                trusted_certificates.as_ptr() as *const c_char,
+                trusted_certificates.len() as c_int,
+            )
+        };
+
+        TlsOptions::from_ptr(ptr)
+    }
+
+    fn from_ptr(ptr: *mut blpapi_TlsOptions_t) -> Result<Self, Error> {
+        if ptr.is_null() {
+            Err(Error::InternalError)
+        } else {
+            Ok(TlsOptions(ptr))
+        }
+    }
+
+    pub fn with_tls_handshake_timeout(self, timeout: Duration) -> Self {
+        unsafe { blpapi_TlsOptions_setTlsHandshakeTimeoutMs(self.0, timeout.as_millis() as c_int) };
+
+        self
+    }
+
+    pub fn with_crl_fetch_timeout(self, timeout: Duration) -> Self {
+        unsafe { blpapi_TlsOptions_setCrlFetchTimeoutMs(self.0, timeout.as_millis() as c_int) };
+
+        self
+    }
+}
+
+impl Clone for TlsOptions {
+    fn clone(&self) -> Self {
+        unsafe { TlsOptions(blpapi_TlsOptions_duplicate(self.0)) }
+    }
+}
+
+impl Drop for TlsOptions {
+    fn drop(&mut self) {
+        unsafe { blpapi_TlsOptions_destroy(self.0) }
+    }
+}
+
+fn path(path: &Path) -> Result<CString, Error> {
+    if !path.is_file() {
+        return Err(Error::NotFound(path.display().to_string()));
+    }
+
+    Ok(CString::new(path.to_string_lossy().as_bytes()).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_credentials() {
+        let res: Result<TlsOptions, Error> =
+            TlsOptions::from_files("/nonexistent/client.p12", "", "/nonexistent/root.p7");
+
+        assert!(matches!(res, Err(Error::NotFound(_))));
+    }
+}
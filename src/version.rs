@@ -0,0 +1,92 @@
+use crate::bindings::*;
+use crate::Error;
+use std::os::raw::c_int;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: i32,
+    pub minor: i32,
+    pub patch: i32,
+    pub build: i32,
+}
+
+impl Version {
+    pub fn new(major: i32, minor: i32, patch: i32, build: i32) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+            build,
+        }
+    }
+
+    pub fn bindings() -> Self {
+        Version::new(
+            BLPAPI_VERSION_MAJOR as i32,
+            BLPAPI_VERSION_MINOR as i32,
+            BLPAPI_VERSION_PATCH as i32,
+            BLPAPI_VERSION_BUILD as i32,
+        )
+    }
+
+    pub fn library() -> Self {
+        let mut major: c_int = 0;
+        let mut minor: c_int = 0;
+        let mut patch: c_int = 0;
+        let mut build: c_int = 0;
+
+        unsafe { blpapi_getVersionInfo(&mut major, &mut minor, &mut patch, &mut build) };
+
+        Version::new(major, minor, patch, build)
+    }
+
+    pub fn is_compatible_with(&self, bindings: &Version) -> bool {
+        self.major == bindings.major && (self.minor, self.patch) >= (bindings.minor, bindings.patch)
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.patch, self.build
+        )
+    }
+}
+
+pub fn check() -> Result<(), Error> {
+    let bindings: Version = Version::bindings();
+    let library: Version = Version::library();
+
+    if !library.is_compatible_with(&bindings) {
+        return Err(Error::VersionMismatch { bindings, library });
+    }
+
+    if library.minor != bindings.minor {
+        log::debug!(
+            "Running blpapi {} with bindings generated for {}...",
+            library,
+            bindings
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compatibility() {
+        let bindings: Version = Version::new(3, 12, 3, 1);
+
+        assert!(Version::new(3, 12, 3, 0).is_compatible_with(&bindings));
+        assert!(Version::new(3, 24, 1, 1).is_compatible_with(&bindings));
+        assert!(!Version::new(3, 11, 9, 1).is_compatible_with(&bindings));
+        assert!(!Version::new(4, 0, 0, 0).is_compatible_with(&bindings));
+
+        assert_eq!(bindings.to_string(), "3.12.3.1");
+    }
+}
@@ -40,4 +40,5 @@
 #include "blpapi_dispatchtbl.h"
 #include "blpapi_identity.h"
 #include "blpapi_schema.h"
-#include "blpapi_topic.h"
\ No newline at end of file
+#include "blpapi_topic.h"
+#include "blpapi_testutil.h"